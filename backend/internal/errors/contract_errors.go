@@ -45,6 +45,12 @@ var bountyEscrowErrors = map[uint32]contractErrorEntry{
 	16: {"InsufficientFunds", "Insufficient funds in the escrow for this operation"},
 	17: {"RefundNotApproved", "Refund has not been approved by an admin"},
 	18: {"FundsPaused", "Bounty fund operations are currently paused"},
+
+	// ExtendedError: codes from 100, past the Error enum's variant limit.
+	100: {"HighValueReleaseRequired", "High-value escrows must be released via propose_release and confirm_release"},
+	101: {"InsufficientAllowance", "Depositor has not approved the escrow contract for the lock amount"},
+	102: {"MetadataTooLarge", "Escrow metadata exceeds its size limits"},
+	103: {"ReleaseLimitReached", "Escrow has reached its per-bounty release limit"},
 }
 
 // ---------------------------------------------------------------------------
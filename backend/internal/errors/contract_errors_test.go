@@ -125,6 +125,10 @@ func TestBountyEscrowSpecificMessages(t *testing.T) {
 		{16, "Insufficient funds"},
 		{17, "not been approved"},
 		{18, "paused"},
+		{100, "propose_release"},
+		{101, "not approved the escrow"},
+		{102, "metadata exceeds"},
+		{103, "release limit"},
 	}
 	for _, tc := range cases {
 		msg := ContractErrorMessage(BountyEscrow, tc.code)
@@ -175,8 +179,8 @@ func TestCircuitBreakerSpecificMessages(t *testing.T) {
 // ---------------------------------------------------------------------------
 
 func TestRegistryCounts(t *testing.T) {
-	if got := len(AllCodes(BountyEscrow)); got != 17 {
-		t.Errorf("BountyEscrow: expected 17 error codes, got %d", got)
+	if got := len(AllCodes(BountyEscrow)); got != 21 {
+		t.Errorf("BountyEscrow: expected 21 error codes, got %d", got)
 	}
 	if got := len(AllCodes(Governance)); got != 14 {
 		t.Errorf("Governance: expected 14 error codes, got %d", got)
@@ -67,7 +67,9 @@ func (ec *EscrowContract) Init(ctx context.Context, adminAddress, tokenAddress s
 	return result, nil
 }
 
-// LockFunds locks funds for a specific bounty
+// LockFunds locks funds for a specific bounty. The contract pulls amount with
+// transfer_from, so the depositor must first approve the escrow contract on
+// the token for at least amount.
 func (ec *EscrowContract) LockFunds(ctx context.Context, depositorAddress string, bountyID uint64, amount int64, deadline int64) (*TransactionResult, error) {
 	ec.client.LogContractInteraction(ec.contractAddress, "lock_funds", map[string]interface{}{
 		"depositor": depositorAddress,
@@ -1,8 +1,25 @@
 use crate::{CapabilityAction, DisputeOutcome, DisputeReason};
-use soroban_sdk::{contracttype, symbol_short, Address, Env};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String};
 
 pub const EVENT_VERSION_V2: u32 = 2;
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EventKey {
+    /// u64 sequence number of the last sequenced event
+    Seq,
+}
+
+/// Returns the next event sequence number, starting at 1.
+///
+/// The counter lives in instance storage and only ever increases, so
+/// indexers can order events that share a ledger timestamp.
+pub fn next_event_seq(env: &Env) -> u64 {
+    let seq: u64 = env.storage().instance().get(&EventKey::Seq).unwrap_or(0) + 1;
+    env.storage().instance().set(&EventKey::Seq, &seq);
+    seq
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct BountyEscrowInitialized {
@@ -21,10 +38,16 @@ pub fn emit_bounty_initialized(env: &Env, event: BountyEscrowInitialized) {
 #[derive(Clone, Debug)]
 pub struct FundsLocked {
     pub version: u32,
+    /// Contract-wide emission order; see `next_event_seq`.
+    pub event_seq: u64,
     pub bounty_id: u64,
     pub amount: i128,
     pub depositor: Address,
     pub deadline: u64,
+    /// `true` when the deposit adds to an already-funded escrow.
+    pub is_topup: bool,
+    /// Caller-supplied reference echoed back for off-chain correlation.
+    pub client_ref: Option<String>,
 }
 
 pub fn emit_funds_locked(env: &Env, event: FundsLocked) {
@@ -36,10 +59,16 @@ pub fn emit_funds_locked(env: &Env, event: FundsLocked) {
 #[derive(Clone, Debug)]
 pub struct FundsReleased {
     pub version: u32,
+    /// Contract-wide emission order; see `next_event_seq`.
+    pub event_seq: u64,
     pub bounty_id: u64,
     pub amount: i128,
     pub recipient: Address,
     pub timestamp: u64,
+    /// Caller-supplied reference echoed back for off-chain correlation.
+    pub client_ref: Option<String>,
+    /// Escrow deadline at the time of release, so indexers need not look it up.
+    pub deadline: u64,
 }
 
 pub fn emit_funds_released(env: &Env, event: FundsReleased) {
@@ -51,10 +80,14 @@ pub fn emit_funds_released(env: &Env, event: FundsReleased) {
 #[derive(Clone, Debug)]
 pub struct FundsRefunded {
     pub version: u32,
+    /// Contract-wide emission order; see `next_event_seq`.
+    pub event_seq: u64,
     pub bounty_id: u64,
     pub amount: i128,
     pub refund_to: Address,
     pub timestamp: u64,
+    /// Escrow deadline at the time of refund, so indexers need not look it up.
+    pub deadline: u64,
 }
 
 pub fn emit_funds_refunded(env: &Env, event: FundsRefunded) {
@@ -218,6 +251,8 @@ pub struct EmergencyWithdrawEvent {
     pub recipient: Address,
     pub amount: i128,
     pub timestamp: u64,
+    /// Token that was drained
+    pub token: Address,
 }
 
 pub fn emit_emergency_withdraw(env: &Env, event: EmergencyWithdrawEvent) {
@@ -455,3 +490,51 @@ pub fn emit_new_cycle_created(env: &Env, event: NewCycleCreatedEvent) {
     let topics = (symbol_short!("new_cyc"), event.new_bounty_id);
     env.events().publish(topics, event.clone());
 }
+
+/// Event emitted when an admin migrates the instance to a new storage version.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractMigrated {
+    pub version: u32,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_contract_migrated(env: &Env, event: ContractMigrated) {
+    let topics = (symbol_short!("migrated"),);
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when an escrow's refund deadline is pushed back.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundDeadlineExtended {
+    pub bounty_id: u64,
+    pub old_deadline: u64,
+    pub new_deadline: u64,
+    pub extended_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_refund_deadline_extended(env: &Env, event: RefundDeadlineExtended) {
+    let topics = (symbol_short!("dl_ext"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when an escrow's metadata is set or replaced.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataSet {
+    pub bounty_id: u64,
+    pub repo_id: u64,
+    pub issue_id: u64,
+    pub bounty_type: String,
+    pub timestamp: u64,
+}
+
+pub fn emit_metadata_set(env: &Env, event: MetadataSet) {
+    let topics = (symbol_short!("meta_set"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
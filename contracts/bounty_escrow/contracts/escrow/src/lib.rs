@@ -13,8 +13,12 @@ mod reentrancy_guard;
 // TODO: test_claim_tickets needs rewrite for soroban-sdk 21 client API
 // #[cfg(test)]
 // mod test_claim_tickets;
+#[cfg(test)]
+mod test_commit_reveal;
 mod test_cross_contract_interface;
 #[cfg(test)]
+mod test_interface_harness;
+#[cfg(test)]
 mod test_multi_token_fees;
 #[cfg(test)]
 mod test_rbac;
@@ -22,17 +26,18 @@ mod traits;
 
 use events::{
     emit_batch_funds_locked, emit_batch_funds_released, emit_bounty_initialized,
-    emit_escrow_archived, emit_escrow_cloned, emit_escrow_locked, emit_escrow_renewed,
-    emit_escrow_unlocked, emit_event_batch, emit_funds_locked, emit_funds_refunded,
-    emit_funds_released, emit_new_cycle_created, emit_ticket_claimed, emit_ticket_issued,
-    ActionSummary, BatchFundsLocked, BatchFundsReleased, BountyEscrowInitialized, ClaimCancelled,
-    ClaimCreated, ClaimExecuted, EscrowArchivedEvent, EscrowClonedEvent, EscrowLockedEvent,
+    emit_contract_migrated, emit_escrow_archived, emit_escrow_cloned, emit_escrow_locked,
+    emit_escrow_renewed, emit_escrow_unlocked, emit_event_batch, emit_funds_locked,
+    emit_funds_refunded, emit_funds_released, emit_new_cycle_created,
+    emit_refund_deadline_extended, emit_ticket_claimed, emit_ticket_issued, ActionSummary,
+    BatchFundsLocked, BatchFundsReleased, BountyEscrowInitialized, ClaimCancelled, ClaimCreated,
+    ClaimExecuted, ContractMigrated, EscrowArchivedEvent, EscrowClonedEvent, EscrowLockedEvent,
     EscrowRenewedEvent, EscrowUnlockedEvent, EventBatch, FundsLocked, FundsRefunded, FundsReleased,
-    NewCycleCreatedEvent, TicketClaimed, TicketIssued, EVENT_VERSION_V2,
+    NewCycleCreatedEvent, RefundDeadlineExtended, TicketClaimed, TicketIssued, EVENT_VERSION_V2,
 };
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token,
+    vec, xdr::ToXdr, Address, Bytes, BytesN, Env, Map, String, Symbol, Vec,
 };
 
 pub(crate) mod monitoring {
@@ -45,6 +50,7 @@ pub(crate) mod monitoring {
     const USER_COUNT: &str = "usr_count";
     #[allow(dead_code)]
     const ERROR_COUNT: &str = "err_count";
+    const EVENTS_ENABLED: &str = "mon_evts";
 
     // Event: Operation metric
     #[contracttype]
@@ -125,6 +131,9 @@ pub(crate) mod monitoring {
                 .set(&err_key, &err_count.checked_add(1).unwrap());
         }
 
+        if !events_enabled(env) {
+            return;
+        }
         env.events().publish(
             (symbol_short!("metric"), symbol_short!("op")),
             OperationMetric {
@@ -153,6 +162,9 @@ pub(crate) mod monitoring {
             .persistent()
             .set(&time_key, &total.checked_add(duration).unwrap());
 
+        if !events_enabled(env) {
+            return;
+        }
         env.events().publish(
             (symbol_short!("metric"), symbol_short!("perf")),
             PerformanceMetric {
@@ -164,6 +176,20 @@ pub(crate) mod monitoring {
         );
     }
 
+    // Metric event switch; counters are updated either way
+    pub fn set_events_enabled(env: &Env, enabled: bool) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(env, EVENTS_ENABLED), &enabled);
+    }
+
+    pub fn events_enabled(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, EVENTS_ENABLED))
+            .unwrap_or(true)
+    }
+
     // Health check
     #[allow(dead_code)]
     pub fn health_check(env: &Env) -> HealthStatus {
@@ -374,7 +400,24 @@ mod anti_abuse {
 }
 
 const MAX_FEE_RATE: i128 = token_math::MAX_FEE_RATE;
-const MAX_BATCH_SIZE: u32 = 20;
+/// Items allowed per batch call unless reconfigured.
+const DEFAULT_MAX_BATCH_SIZE: u32 = 20;
+/// Largest batch size `set_max_batch_size` accepts.
+const MAX_BATCH_SIZE_LIMIT: u32 = 500;
+/// Refund records kept inline in an `Escrow` unless reconfigured.
+const DEFAULT_REFUND_HISTORY_CAP: u32 = 20;
+/// Refund records per archived page.
+const REFUND_ARCHIVE_PAGE_SIZE: u32 = 20;
+/// Status transitions kept per escrow; the oldest are dropped beyond this.
+const STATUS_HISTORY_CAP: u32 = 32;
+/// Bounty ids per page of the status index.
+const STATUS_INDEX_PAGE_SIZE: u32 = 50;
+/// Maximum length in bytes of a caller-supplied client reference.
+const MAX_CLIENT_REF_LEN: u32 = 64;
+/// Maximum length in bytes of `EscrowMetadata::bounty_type`.
+const MAX_BOUNTY_TYPE_LEN: u32 = 32;
+/// Storage schema version written at init; bump when a migration is needed.
+pub const CONTRACT_VERSION: u32 = 1;
 
 extern crate grainlify_core;
 use grainlify_core::asset;
@@ -397,13 +440,15 @@ pub enum Error {
     DuplicateBountyId = 12,
     /// Returned when amount is invalid (zero, negative, or exceeds available)
     InvalidAmount = 13,
-    /// Returned when deadline is invalid (in the past or too far in the future)
+    /// Returned when deadline is invalid (in the past, or closer or further
+    /// than the configured deadline limits)
     InvalidDeadline = 14,
-    /// Reserved for future use (keeps error code sequence contiguous for indexers)
-    Reserved = 15,
+    /// Returned when the payout allowlist is enabled and the recipient is not on it
+    RecipientNotAllowed = 15,
     /// Returned when contract has insufficient funds for the operation
     InsufficientFunds = 16,
-    /// Returned when refund is attempted without admin approval
+    /// Returned when refund is attempted without admin approval, or when
+    /// approving an early refund the depositor has not requested
     RefundNotApproved = 17,
     FundsPaused = 18,
     /// Returned when lock amount is below the configured policy minimum (Issue #62)
@@ -424,8 +469,11 @@ pub enum Error {
     CapabilityRevoked = 28,
     CapabilityActionMismatch = 29,
     CapabilityAmountExceeded = 30,
+    /// Returned when a capability has no uses left, or when an escrow has
+    /// already had `max_releases_per_bounty` partial releases
     CapabilityUsesExhausted = 31,
     CapabilityExceedsAuthority = 32,
+    /// Returned when an asset id, or a classic asset code or issuer, is malformed
     InvalidAssetId = 33,
     /// Returned when escrow is locked by owner/admin (Issue #675)
     EscrowLocked = 34,
@@ -437,6 +485,53 @@ pub enum Error {
     RenewalNotAllowed = 37,
     /// Returned when renewal parameters are invalid (Issue #679)
     InvalidRenewal = 38,
+    /// Returned when the caller's expected token does not match the escrow token
+    UnexpectedToken = 39,
+    /// Returned when revealing a release that was never committed, when
+    /// confirming a release that was never proposed, or when releasing
+    /// directly while a reveal delay makes commit-reveal mandatory
+    CommitmentNotFound = 40,
+    /// Returned when revealed release parameters do not hash to the commitment
+    CommitmentMismatch = 41,
+    /// Returned when a release is revealed or confirmed before its delay has elapsed
+    RevealTooEarly = 42,
+    /// Returned when editing metadata of an escrow that is Released or Refunded
+    EscrowTerminal = 43,
+    /// Returned when releasing an escrow locked more recently than the minimum lifetime
+    EscrowTooYoung = 44,
+    /// Returned when a client reference or metadata string exceeds its length limit
+    ClientRefTooLong = 45,
+    /// Returned when migrating to a version lower than the stored one
+    InvalidVersion = 46,
+    /// Returned when releasing or refunding an escrow that is under dispute
+    EscrowDisputed = 47,
+    /// Returned when resolving an escrow that is not under dispute
+    NotDisputed = 48,
+    /// Returned when a payout resolution does not name a recipient
+    DisputeRecipientRequired = 49,
+    /// Returned when the depositor's token balance is below the lock amount
+    InsufficientBalance = 50,
+}
+
+/// Errors added after `Error` reached the contract spec's 50-variant limit.
+///
+/// Codes start at 100 so they never collide with `Error`. Entry points that
+/// return `Error` raise these with `panic_with_error!`; clients see them as
+/// `Error(Contract, #N)` like any other contract error.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExtendedError {
+    /// Returned when an escrow above the high-value threshold is released
+    /// through any path other than `propose_release` / `confirm_release`
+    HighValueReleaseRequired = 100,
+    /// Returned when the depositor has not approved the contract to pull the
+    /// lock amount
+    InsufficientAllowance = 101,
+    /// Returned when escrow metadata exceeds its size limits
+    MetadataTooLarge = 102,
+    /// Returned when an escrow has used up its per-bounty release cap
+    ReleaseLimitReached = 103,
 }
 
 #[contracttype]
@@ -456,6 +551,22 @@ pub enum EscrowStatus {
     PartiallyRefunded,
     /// Template escrow created by clone; no funds yet (Issue #678)
     Template,
+    /// Frozen by the admin pending dispute resolution; funds stay escrowed
+    Disputed,
+}
+
+impl EscrowStatus {
+    /// Stable numeric code used by `traits::StatusQuery::get_status`.
+    pub fn code(&self) -> u32 {
+        match self {
+            EscrowStatus::Locked => 1,
+            EscrowStatus::Released => 2,
+            EscrowStatus::Refunded => 3,
+            EscrowStatus::PartiallyRefunded => 4,
+            EscrowStatus::Template => 5,
+            EscrowStatus::Disputed => 6,
+        }
+    }
 }
 
 #[contracttype]
@@ -520,6 +631,134 @@ pub enum DataKey {
     CycleLink(u64),
     /// How many times an escrow has been renewed (Issue #679): bounty_id -> u32
     CycleCount(u64),
+
+    /// Pending commit-reveal release: bounty_id -> ReleaseCommitment
+    ReleaseCommitment(u64),
+    /// Minimum seconds between commit_release and reveal_release (u64)
+    RevealDelay,
+
+    /// Timestamp at which an escrow was funded: bounty_id -> u64
+    CreatedAt(u64),
+    /// Minimum seconds an escrow must be locked before release (u64)
+    MinEscrowLifetime,
+
+    /// Max refund records kept inline in an escrow (u32)
+    RefundHistoryCap,
+    /// Archived refund records: (bounty_id, page) -> Vec<RefundRecord>
+    RefundArchive(u64, u32),
+    /// Number of archived refund records: bounty_id -> u32
+    RefundArchiveLen(u64),
+    /// Status transitions: bounty_id -> Vec<(EscrowStatus, timestamp)>
+    StatusHistory(u64),
+
+    /// Whether addresses other than the depositor may top up escrows (bool)
+    AllowThirdPartyTopUp,
+    /// Per-contributor funding of a topped-up escrow: bounty_id -> Map<Address, i128>
+    Contributors(u64),
+    /// Storage schema version of this instance (u32)
+    ContractVersion,
+    /// Max items per batch_lock_funds / batch_release_funds call (u32)
+    MaxBatchSize,
+    /// Status an escrow held before `open_dispute`, restored on resolution
+    DisputedFrom(u64),
+    /// Cumulative amount released from an escrow: bounty_id -> i128
+    ReleasedAmount(u64),
+    /// Number of escrows currently holding funds (u64)
+    ActiveEscrowCount,
+    /// Seconds after the deadline during which only the admin may refund (u64)
+    RefundGracePeriod,
+}
+
+/// Storage keys for the release recipient allowlist. Kept apart from
+/// `DataKey`, which is at the contract spec's 50-variant limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PayoutAllowlistKey {
+    /// Whether releases are restricted to allowlisted recipients (bool)
+    Enabled,
+    /// Recipient approved for releases: address -> bool
+    Address(Address),
+}
+
+/// Storage keys for the propose/confirm flow on high-value releases. Kept
+/// apart from `DataKey`, which is at the contract spec's 50-variant limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HighValueReleaseKey {
+    /// Threshold and time lock (HighValueReleaseConfig)
+    Config,
+    /// Proposed release awaiting confirmation: bounty_id -> PendingRelease
+    Pending(u64),
+}
+
+/// Depositor requests to cancel a bounty before its deadline. Kept apart
+/// from `DataKey`, which is at the contract spec's 50-variant limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EarlyRefundKey {
+    /// bounty_id -> u64 timestamp of the depositor's request
+    Requested(u64),
+}
+
+/// Per-escrow partial release cap. Kept apart from `DataKey`, which is at
+/// the contract spec's 50-variant limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReleaseLimitKey {
+    /// Maximum partial releases per escrow (u32, 0 = unlimited)
+    MaxPerBounty,
+    /// bounty_id -> u32 partial releases made so far
+    Count(u64),
+}
+
+/// Lock deadline bounds. Kept apart from `DataKey`, which is at the
+/// contract spec's 50-variant limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DeadlineLimitKey {
+    /// (min_duration, max_duration) in seconds from lock time; 0 = unbounded
+    Limits,
+}
+
+/// Paged index of bounty ids by current status. Each status keeps a dense
+/// list of `STATUS_INDEX_PAGE_SIZE`-entry pages, so a transition touches at
+/// most two pages however many escrows share the status.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StatusIndexKey {
+    /// (status code, page number) -> Vec<u64> of bounty ids
+    Page(u32, u32),
+    /// status code -> u32 number of indexed bounty ids
+    Len(u32),
+    /// bounty_id -> u32 position in its current status list
+    Position(u64),
+}
+
+/// Cached token metadata. Kept apart from `DataKey`, which is at the
+/// contract spec's 50-variant limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TokenInfoKey {
+    /// token -> u32 decimals, read once from the token contract
+    Decimals(Address),
+}
+
+/// Escrows whose remaining amount exceeds `high_value_threshold` must be
+/// released through `propose_release` / `confirm_release`. A threshold of
+/// zero disables the flow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HighValueReleaseConfig {
+    pub high_value_threshold: i128,
+    pub time_lock_duration: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingRelease {
+    pub contributor: Address,
+    pub amount: i128,
+    pub proposed_at: u64,
 }
 
 #[contracttype]
@@ -550,6 +789,29 @@ pub struct AggregateStats {
     pub count_refunded: u32,
 }
 
+/// Escrow fields needed by list views, without the refund history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowSummary {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+    pub remaining_amount: i128,
+    pub status: EscrowStatus,
+    pub deadline: u64,
+    pub token_address: Address,
+}
+
+/// Per-depositor totals; `total_locked` is what the contract still holds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositorSummary {
+    pub active_count: u32,
+    pub total_locked: i128,
+    pub total_refunded: i128,
+    pub total_released: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PauseStateChanged {
@@ -618,6 +880,15 @@ pub struct MultisigConfig {
     pub required_signatures: u32,
 }
 
+/// A committed-but-unrevealed release. `hash` is
+/// `sha256(xdr(contributor, amount, salt))`, see `compute_release_commitment`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseCommitment {
+    pub hash: BytesN<32>,
+    pub committed_at: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ReleaseApproval {
@@ -803,6 +1074,9 @@ impl BountyEscrowContract {
         env.storage()
             .instance()
             .set(&DataKey::Token, &normalized_token);
+        env.storage()
+            .instance()
+            .set(&DataKey::ContractVersion, &CONTRACT_VERSION);
 
         emit_bounty_initialized(
             &env,
@@ -855,6 +1129,9 @@ impl BountyEscrowContract {
         env.storage()
             .instance()
             .set(&DataKey::NetworkId, &network_id);
+        env.storage()
+            .instance()
+            .set(&DataKey::ContractVersion, &CONTRACT_VERSION);
 
         emit_bounty_initialized(
             &env,
@@ -869,8 +1146,52 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Storage schema version of this instance.
+    ///
+    /// Instances initialized before versioning was recorded report
+    /// `CONTRACT_VERSION`.
+    pub fn get_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ContractVersion)
+            .unwrap_or(CONTRACT_VERSION)
+    }
+
+    /// Record that this instance now uses storage schema `new_version`
+    /// (admin only). Emits `ContractMigrated` so indexers can follow.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract has no admin
+    /// * `InvalidVersion` - `new_version` is lower than the stored version
+    pub fn migrate(env: Env, new_version: u32) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let from_version = Self::get_version(env.clone());
+        if new_version < from_version {
+            return Err(Error::InvalidVersion);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ContractVersion, &new_version);
+
+        emit_contract_migrated(
+            &env,
+            ContractMigrated {
+                version: EVENT_VERSION_V2,
+                from_version,
+                to_version: new_version,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
     /// Calculate fee using floor rounding. Delegates to `token_math::calculate_fee`.
-    #[allow(dead_code)]
     fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
         token_math::calculate_fee(amount, fee_rate)
     }
@@ -1046,27 +1367,11 @@ impl BountyEscrowContract {
         }
 
         let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::TokenClient::new(&env, &token_address);
-
-        let contract_address = env.current_contract_address();
-        let balance = token_client.balance(&contract_address);
-
-        if balance > 0 {
-            // INTERACTION: external token transfer is last
-            token_client.transfer(&contract_address, &target, &balance);
-            events::emit_emergency_withdraw(
-                &env,
-                events::EmergencyWithdrawEvent {
-                    admin,
-                    recipient: target,
-                    amount: balance,
-                    timestamp: env.ledger().timestamp(),
-                },
-            );
-        }
+        Self::drain_token(&env, admin, &token_address, target);
 
         // Zero out all active escrows to maintain INV-2 invariant.
         // The funds have been withdrawn, so escrow records must reflect this.
+        // Disputed escrows hold funds too, and their hold is dropped.
         let index: Vec<u64> = env
             .storage()
             .persistent()
@@ -1078,11 +1383,13 @@ impl BountyEscrowContract {
                 .persistent()
                 .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
             {
-                if escrow.status == EscrowStatus::Locked
-                    || escrow.status == EscrowStatus::PartiallyRefunded
-                {
+                if Self::is_active_status(&escrow.status) {
+                    env.storage()
+                        .persistent()
+                        .remove(&DataKey::DisputedFrom(bounty_id));
                     escrow.remaining_amount = 0;
                     escrow.status = EscrowStatus::Refunded;
+                    Self::record_status_transition(&env, bounty_id, EscrowStatus::Refunded);
                     env.storage()
                         .persistent()
                         .set(&DataKey::Escrow(bounty_id), &escrow);
@@ -1095,6 +1402,68 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Emergency withdraw the full contract balance of `token` (admin only,
+    /// must have lock_paused = true).
+    ///
+    /// For the escrow token this is `emergency_withdraw`, which also zeroes
+    /// the escrow records. Any other token can only have reached the contract
+    /// by direct transfer, so no escrow state refers to it and it is simply
+    /// swept to `target`.
+    pub fn emergency_withdraw_token(
+        env: Env,
+        token: Address,
+        target: Address,
+    ) -> Result<(), Error> {
+        let escrow_token: Option<Address> = env.storage().instance().get(&DataKey::Token);
+        if escrow_token.as_ref() == Some(&token) {
+            return Self::emergency_withdraw(env, target);
+        }
+
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let flags = Self::get_pause_flags(&env);
+        if !flags.lock_paused {
+            return Err(Error::NotPaused);
+        }
+
+        Self::drain_token(&env, admin, &token, target);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Transfers the whole contract balance of `token` to `target` and emits
+    /// an emergency withdrawal event; no-op when the balance is zero.
+    fn drain_token(env: &Env, admin: Address, token: &Address, target: Address) {
+        let token_client = token::TokenClient::new(env, token);
+        let contract_address = env.current_contract_address();
+        let balance = token_client.balance(&contract_address);
+
+        if balance > 0 {
+            // INTERACTION: external token transfer is last
+            token_client.transfer(&contract_address, &target, &balance);
+            events::emit_emergency_withdraw(
+                env,
+                events::EmergencyWithdrawEvent {
+                    admin,
+                    recipient: target,
+                    amount: balance,
+                    timestamp: env.ledger().timestamp(),
+                    token: token.clone(),
+                },
+            );
+        }
+    }
+
     /// Get current pause flags
     pub fn get_pause_flags(env: &Env) -> PauseFlags {
         env.storage()
@@ -1315,6 +1684,7 @@ impl BountyEscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(new_bounty_id), &template);
+        Self::record_status_transition(&env, new_bounty_id, EscrowStatus::Template);
         let mut index: Vec<u64> = env
             .storage()
             .persistent()
@@ -1714,6 +2084,31 @@ impl BountyEscrowContract {
         Self::get_fee_config_internal(&env)
     }
 
+    /// Preview a lock of `amount` under the current fee configuration.
+    ///
+    /// Returns `(net, fee)`. The fee is zero while fees are disabled.
+    pub fn estimate_lock(env: Env, amount: i128) -> (i128, i128) {
+        let config = Self::get_fee_config_internal(&env);
+        Self::estimate_with_rate(amount, config.fee_enabled, config.lock_fee_rate)
+    }
+
+    /// Preview a release of `amount` under the current fee configuration.
+    ///
+    /// Returns `(net, fee)`. The fee is zero while fees are disabled.
+    pub fn estimate_release(env: Env, amount: i128) -> (i128, i128) {
+        let config = Self::get_fee_config_internal(&env);
+        Self::estimate_with_rate(amount, config.fee_enabled, config.release_fee_rate)
+    }
+
+    fn estimate_with_rate(amount: i128, fee_enabled: bool, fee_rate: i128) -> (i128, i128) {
+        let fee = if fee_enabled && fee_rate > 0 {
+            Self::calculate_fee(amount, fee_rate)
+        } else {
+            0
+        };
+        (amount - fee, fee)
+    }
+
     /// Retrieves the chain identifier.
     ///
     /// # Arguments
@@ -1870,6 +2265,14 @@ impl BountyEscrowContract {
 
     /// Lock funds for a specific bounty.
     ///
+    /// The contract pulls `amount` with `transfer_from`, so the depositor
+    /// must first `approve` the contract on the token for at least `amount`.
+    ///
+    /// # Errors
+    /// * `ExtendedError::InsufficientAllowance` - the depositor's allowance
+    ///   for the contract is below `amount`
+    /// * `InsufficientBalance` - the depositor's balance is below `amount`
+    ///
     /// # Reentrancy
     /// Protected by the shared reentrancy guard. State (escrow record,
     /// indexes) is written before the inbound token transfer so that
@@ -1881,44 +2284,314 @@ impl BountyEscrowContract {
         amount: i128,
         deadline: u64,
     ) -> Result<(), Error> {
-        let res =
-            Self::lock_funds_logic(env.clone(), depositor.clone(), bounty_id, amount, deadline);
+        let res = Self::lock_funds_logic(
+            env.clone(),
+            depositor.clone(),
+            bounty_id,
+            amount,
+            deadline,
+            None,
+        );
         monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
         res
     }
 
-    fn lock_funds_logic(
+    /// Lock funds for a specific bounty, echoing `client_ref` in the
+    /// `FundsLocked` event so a backend can match it to its own request.
+    ///
+    /// # Errors
+    /// * `ClientRefTooLong` - `client_ref` exceeds `MAX_CLIENT_REF_LEN` bytes
+    /// * Same as `lock_funds`
+    pub fn lock_funds_with_ref(
         env: Env,
         depositor: Address,
         bounty_id: u64,
         amount: i128,
         deadline: u64,
+        client_ref: Option<String>,
     ) -> Result<(), Error> {
-        // GUARD: acquire reentrancy lock
-        reentrancy_guard::acquire(&env);
-
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, depositor.clone());
+        Self::validate_client_ref(&client_ref)?;
+        let res = Self::lock_funds_logic(
+            env.clone(),
+            depositor.clone(),
+            bounty_id,
+            amount,
+            deadline,
+            client_ref,
+        );
+        monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
+        res
+    }
 
-        if Self::check_paused(&env, symbol_short!("lock")) {
-            return Err(Error::FundsPaused);
+    fn validate_client_ref(client_ref: &Option<String>) -> Result<(), Error> {
+        match client_ref {
+            Some(r) if r.len() > MAX_CLIENT_REF_LEN => Err(Error::ClientRefTooLong),
+            _ => Ok(()),
         }
+    }
 
-        let _start = env.ledger().timestamp();
-        let _caller = depositor.clone();
-
-        // Verify depositor authorization
-        depositor.require_auth();
+    /// Lock funds and attach metadata in a single call.
+    ///
+    /// Metadata is validated before anything else, so an oversized value
+    /// aborts the lock without moving funds. Emits `FundsLocked` followed by
+    /// `MetadataSet`.
+    ///
+    /// # Errors
+    /// * `ExtendedError::MetadataTooLarge` - `bounty_type` exceeds
+    ///   `MAX_BOUNTY_TYPE_LEN` bytes
+    /// * Same as `lock_funds`
+    pub fn lock_funds_with_metadata(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        metadata: EscrowMetadata,
+    ) -> Result<(), Error> {
+        Self::validate_metadata_size(&env, &metadata);
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+        Self::store_metadata(&env, bounty_id, metadata);
+        Ok(())
+    }
 
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+    fn validate_metadata_size(env: &Env, metadata: &EscrowMetadata) {
+        if metadata.bounty_type.len() > MAX_BOUNTY_TYPE_LEN {
+            panic_with_error!(env, ExtendedError::MetadataTooLarge);
         }
+    }
 
-        // Allow filling a Template escrow (clone) with same depositor (Issue #678).
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            let existing: Escrow = env
+    fn store_metadata(env: &Env, bounty_id: u64, metadata: EscrowMetadata) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Metadata(bounty_id), &metadata);
+        events::emit_metadata_set(
+            env,
+            events::MetadataSet {
+                bounty_id,
+                repo_id: metadata.repo_id,
+                issue_id: metadata.issue_id,
+                bounty_type: metadata.bounty_type,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Lock funds for a specific bounty, asserting the escrow token first.
+    ///
+    /// When `expected_token` is `Some`, it must equal the token this contract
+    /// escrows or the call fails with `Error::UnexpectedToken` before any
+    /// state change. `None` behaves exactly like `lock_funds`.
+    pub fn lock_funds_with_expected_token(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        expected_token: Option<Address>,
+    ) -> Result<(), Error> {
+        if let Some(expected) = expected_token {
+            let token_addr: Address = env
                 .storage()
-                .persistent()
+                .instance()
+                .get(&DataKey::Token)
+                .ok_or(Error::NotInitialized)?;
+            if expected != token_addr {
+                return Err(Error::UnexpectedToken);
+            }
+        }
+        Self::lock_funds(env, depositor, bounty_id, amount, deadline)
+    }
+
+    /// Lock funds given as a classic Stellar asset (`asset_code` issued by
+    /// `asset_issuer`).
+    ///
+    /// Resolves the asset's Stellar Asset Contract address on-chain, so
+    /// clients need not derive it, then locks through `lock_funds`. The
+    /// resolved contract must be the token this escrow was initialised with,
+    /// otherwise the call fails with `Error::UnexpectedToken`. A code that is
+    /// empty or longer than 12 bytes, or an issuer that is not an account,
+    /// fails with `Error::InvalidAssetId`.
+    pub fn lock_classic_asset(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        asset_issuer: Address,
+        asset_code: String,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        let token_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        let serialized_asset = Self::classic_asset_xdr(&env, &asset_issuer, &asset_code)?;
+        let sac = env
+            .deployer()
+            .with_stellar_asset(serialized_asset)
+            .deployed_address();
+        if sac != token_addr {
+            return Err(Error::UnexpectedToken);
+        }
+        Self::lock_funds(env, depositor, bounty_id, amount, deadline)
+    }
+
+    /// Serialises a classic asset as `Asset` XDR (AlphaNum4 or AlphaNum12).
+    fn classic_asset_xdr(env: &Env, issuer: &Address, code: &String) -> Result<Bytes, Error> {
+        let code_len = code.len() as usize;
+        if code_len == 0 || code_len > 12 {
+            return Err(Error::InvalidAssetId);
+        }
+        // An account address serialises as ScVal::Address(ScAddress::Account(
+        // PublicKey::Ed25519(key))): three 4-byte tags followed by the key.
+        let issuer_xdr = issuer.clone().to_xdr(env);
+        if issuer_xdr.len() != 44 || issuer_xdr.get(7) != Some(0) {
+            return Err(Error::InvalidAssetId);
+        }
+
+        let mut code_buf = [0u8; 12];
+        code.copy_into_slice(&mut code_buf[..code_len]);
+        let (asset_type, padded_len) = if code_len <= 4 { (1u8, 4) } else { (2u8, 12) };
+
+        let mut asset = Bytes::from_array(env, &[0, 0, 0, asset_type]);
+        asset.extend_from_slice(&code_buf[..padded_len]);
+        asset.append(&issuer_xdr.slice(8..));
+        Ok(asset)
+    }
+
+    /// Add funds to an existing locked escrow.
+    ///
+    /// Only the original depositor may top up unless third-party top-ups are
+    /// enabled with `set_allow_third_party_topup`, and only while the escrow
+    /// is `Locked`. Both `amount` and `remaining_amount` grow by `amount`; the
+    /// deadline is unchanged. Each contributor's share is tracked so refunds
+    /// after the deadline are split pro-rata (see `get_contributors`).
+    /// Emits `FundsLocked` with `is_topup = true`.
+    ///
+    /// # Errors
+    /// * `FundsPaused` - lock operations are paused
+    /// * `InvalidAmount` - `amount` is not positive
+    /// * `BountyNotFound` - no escrow exists for `bounty_id`
+    /// * `FundsNotLocked` - escrow is not in `Locked` status
+    /// * `Unauthorized` - `depositor` is not the escrow's depositor
+    pub fn top_up_funds(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+    ) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        anti_abuse::check_rate_limit(&env, depositor.clone());
+
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused);
+        }
+
+        depositor.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.depositor != depositor && !Self::get_allow_third_party_topup(env.clone()) {
+            return Err(Error::Unauthorized);
+        }
+
+        // EFFECTS
+        let mut contributors = Self::get_contributors(env.clone(), bounty_id)?;
+        let contributed = contributors.get(depositor.clone()).unwrap_or(0);
+        contributors.set(
+            depositor.clone(),
+            contributed
+                .checked_add(amount)
+                .ok_or(Error::InvalidAmount)?,
+        );
+        env.storage()
+            .persistent()
+            .set(&DataKey::Contributors(bounty_id), &contributors);
+        escrow.amount = escrow
+            .amount
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        escrow.remaining_amount = escrow
+            .remaining_amount
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let event_seq = events::next_event_seq(&env);
+        client.transfer(&depositor, &env.current_contract_address(), &amount);
+
+        emit_funds_locked(
+            &env,
+            FundsLocked {
+                version: EVENT_VERSION_V2,
+                event_seq,
+                bounty_id,
+                amount,
+                depositor,
+                deadline: escrow.deadline,
+                is_topup: true,
+                client_ref: None,
+            },
+        );
+
+        multitoken_invariants::assert_after_lock(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    fn lock_funds_logic(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        client_ref: Option<String>,
+    ) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(&env, depositor.clone());
+
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused);
+        }
+
+        let _start = env.ledger().timestamp();
+        let _caller = depositor.clone();
+
+        // Verify depositor authorization
+        depositor.require_auth();
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        // Allow filling a Template escrow (clone) with same depositor (Issue #678).
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            let existing: Escrow = env
+                .storage()
+                .persistent()
                 .get(&DataKey::Escrow(bounty_id))
                 .unwrap();
             if existing.status == EscrowStatus::Template && existing.depositor == depositor {
@@ -1938,6 +2611,8 @@ impl BountyEscrowContract {
                 if amount <= 0 {
                     return Err(Error::InvalidAmount);
                 }
+                Self::ensure_depositor_allowance(&env, &depositor, amount);
+                Self::ensure_depositor_balance(&env, &depositor, amount)?;
                 let escrow = Escrow {
                     depositor: depositor.clone(),
                     amount,
@@ -1950,17 +2625,26 @@ impl BountyEscrowContract {
                 env.storage()
                     .persistent()
                     .set(&DataKey::Escrow(bounty_id), &escrow);
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::CreatedAt(bounty_id), &env.ledger().timestamp());
+                Self::record_status_transition(&env, bounty_id, EscrowStatus::Locked);
                 let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
                 let client = token::Client::new(&env, &token_addr);
-                client.transfer(&depositor, &env.current_contract_address(), &amount);
+                let event_seq = events::next_event_seq(&env);
+                let contract_address = env.current_contract_address();
+                client.transfer_from(&contract_address, &depositor, &contract_address, &amount);
                 emit_funds_locked(
                     &env,
                     FundsLocked {
                         version: EVENT_VERSION_V2,
+                        event_seq,
                         bounty_id,
                         amount,
                         depositor: depositor.clone(),
                         deadline: existing.deadline,
+                        is_topup: false,
+                        client_ref: client_ref.clone(),
                     },
                 );
                 multitoken_invariants::assert_after_lock(&env);
@@ -1985,6 +2669,10 @@ impl BountyEscrowContract {
                 return Err(Error::AmountAboveMaximum);
             }
         }
+        Self::ensure_deadline_within_limits(&env, deadline)?;
+
+        Self::ensure_depositor_allowance(&env, &depositor, amount);
+        Self::ensure_depositor_balance(&env, &depositor, amount)?;
 
         // EFFECTS: write escrow state and indexes before the external call
         let escrow = Escrow {
@@ -2000,6 +2688,10 @@ impl BountyEscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::CreatedAt(bounty_id), &env.ledger().timestamp());
+        Self::record_status_transition(&env, bounty_id, EscrowStatus::Locked);
 
         // Update indexes
         let mut index: Vec<u64> = env
@@ -2026,17 +2718,22 @@ impl BountyEscrowContract {
         // INTERACTION: external token transfer is last
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
-        client.transfer(&depositor, &env.current_contract_address(), &amount);
+        let event_seq = events::next_event_seq(&env);
+        let contract_address = env.current_contract_address();
+        client.transfer_from(&contract_address, &depositor, &contract_address, &amount);
 
         // Emit value allows for off-chain indexing
         emit_funds_locked(
             &env,
             FundsLocked {
                 version: EVENT_VERSION_V2,
+                event_seq,
                 bounty_id,
                 amount,
                 depositor: depositor.clone(),
                 deadline,
+                is_topup: false,
+                client_ref,
             },
         );
 
@@ -2048,6 +2745,28 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Fails with `ExtendedError::InsufficientAllowance` instead of letting
+    /// `transfer_from` trap when the depositor has not approved the contract
+    /// to pull `amount`.
+    fn ensure_depositor_allowance(env: &Env, depositor: &Address, amount: i128) {
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let allowance = token::Client::new(env, &token_addr)
+            .allowance(depositor, &env.current_contract_address());
+        if allowance < amount {
+            panic_with_error!(env, ExtendedError::InsufficientAllowance);
+        }
+    }
+
+    /// Fails with `InsufficientBalance` instead of letting the token
+    /// transfer trap when the depositor cannot cover `amount`.
+    fn ensure_depositor_balance(env: &Env, depositor: &Address, amount: i128) -> Result<(), Error> {
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        if token::Client::new(env, &token_addr).balance(depositor) < amount {
+            return Err(Error::InsufficientBalance);
+        }
+        Ok(())
+    }
+
     /// Release funds to the contributor.
     /// Only the admin (backend) can authorize this.
     ///
@@ -2055,12 +2774,36 @@ impl BountyEscrowContract {
     /// Protected by the shared reentrancy guard. Escrow state is updated
     /// to `Released` *before* the outbound token transfer (CEI pattern).
     pub fn release_funds(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
-        let res = Self::release_funds_logic(env.clone(), bounty_id, contributor.clone());
+        let res = Self::release_funds_logic(env.clone(), bounty_id, contributor.clone(), None);
+        monitoring::track_operation(&env, symbol_short!("release"), contributor, res.is_ok());
+        res
+    }
+
+    /// Release funds to the contributor, echoing `client_ref` in the
+    /// `FundsReleased` event.
+    ///
+    /// # Errors
+    /// * `ClientRefTooLong` - `client_ref` exceeds `MAX_CLIENT_REF_LEN` bytes
+    /// * Same as `release_funds`
+    pub fn release_funds_with_ref(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        client_ref: Option<String>,
+    ) -> Result<(), Error> {
+        Self::validate_client_ref(&client_ref)?;
+        let res =
+            Self::release_funds_logic(env.clone(), bounty_id, contributor.clone(), client_ref);
         monitoring::track_operation(&env, symbol_short!("release"), contributor, res.is_ok());
         res
     }
 
-    fn release_funds_logic(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+    fn release_funds_logic(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        client_ref: Option<String>,
+    ) -> Result<(), Error> {
         if Self::check_paused(&env, symbol_short!("release")) {
             return Err(Error::FundsPaused);
         }
@@ -2096,6 +2839,23 @@ impl BountyEscrowContract {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
+        Self::ensure_not_high_value(&env, bounty_id);
+        Self::ensure_reveal_not_required(&env)?;
+        Self::release_remaining_to(&env, bounty_id, &contributor, client_ref)?;
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Pays an escrow's remaining amount to `contributor` and marks it
+    /// Released. Callers handle authorization and the reentrancy guard.
+    fn release_remaining_to(
+        env: &Env,
+        bounty_id: u64,
+        contributor: &Address,
+        client_ref: Option<String>,
+    ) -> Result<(), Error> {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
@@ -2106,15 +2866,23 @@ impl BountyEscrowContract {
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
 
+        if escrow.status == EscrowStatus::Disputed {
+            return Err(Error::EscrowDisputed);
+        }
         if escrow.status != EscrowStatus::Locked {
             return Err(Error::FundsNotLocked);
         }
+        Self::ensure_min_lifetime_elapsed(env, bounty_id)?;
+        Self::ensure_payout_allowed(env, contributor)?;
 
         // EFFECTS: update state before external call (CEI)
-        let release_amount = escrow.amount;
+        // Pay out what is left after any earlier partial releases
+        let release_amount = escrow.remaining_amount;
         escrow.status = EscrowStatus::Released;
+        Self::record_status_transition(env, bounty_id, EscrowStatus::Released);
         escrow.remaining_amount = 0;
-        invariants::assert_escrow(&env, &escrow);
+        Self::record_released_amount(env, bounty_id, release_amount);
+        invariants::assert_escrow(env, &escrow);
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
@@ -2125,34 +2893,39 @@ impl BountyEscrowContract {
 
         // INTERACTION: external token transfer is last
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
+        let client = token::Client::new(env, &token_addr);
+        let event_seq = events::next_event_seq(env);
         client.transfer(
             &env.current_contract_address(),
-            &contributor,
+            contributor,
             &release_amount,
         );
 
         emit_funds_released(
-            &env,
+            env,
             FundsReleased {
                 version: EVENT_VERSION_V2,
+                event_seq,
                 bounty_id,
                 amount: release_amount,
                 recipient: contributor.clone(),
                 timestamp: env.ledger().timestamp(),
+                client_ref,
+                deadline: escrow.deadline,
             },
         );
 
         // INV-2: Verify aggregate balance matches token balance after release
-        multitoken_invariants::assert_after_disbursement(&env);
-
-        // GUARD: release reentrancy lock
-        reentrancy_guard::release(&env);
+        multitoken_invariants::assert_after_disbursement(env);
         Ok(())
     }
 
     /// Delegated release flow using a capability instead of admin auth.
     /// The capability amount limit is consumed by `payout_amount`.
+    ///
+    /// # Errors
+    /// * `ExtendedError::ReleaseLimitReached` - the escrow has used up the
+    ///   cap set by `set_max_releases_per_bounty`
     pub fn release_with_capability(
         env: Env,
         bounty_id: u64,
@@ -2185,6 +2958,11 @@ impl BountyEscrowContract {
         if payout_amount > escrow.remaining_amount {
             return Err(Error::InsufficientFunds);
         }
+        Self::ensure_not_high_value(&env, bounty_id);
+        Self::ensure_reveal_not_required(&env)?;
+        Self::ensure_min_lifetime_elapsed(&env, bounty_id)?;
+        Self::ensure_payout_allowed(&env, &contributor)?;
+        Self::bump_release_count(&env, bounty_id);
 
         Self::consume_capability(
             &env,
@@ -2195,17 +2973,12 @@ impl BountyEscrowContract {
             payout_amount,
         )?;
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &contributor,
-            &payout_amount,
-        );
-
+        // EFFECTS: update state before external call (CEI)
         escrow.remaining_amount -= payout_amount;
+        Self::record_released_amount(&env, bounty_id, payout_amount);
         if escrow.remaining_amount == 0 {
             escrow.status = EscrowStatus::Released;
+            Self::record_status_transition(&env, bounty_id, EscrowStatus::Released);
             let now_ts = env.ledger().timestamp();
             env.storage()
                 .persistent()
@@ -2215,14 +2988,27 @@ impl BountyEscrowContract {
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
 
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let event_seq = events::next_event_seq(&env);
+        client.transfer(
+            &env.current_contract_address(),
+            &contributor,
+            &payout_amount,
+        );
+
         emit_funds_released(
             &env,
             FundsReleased {
                 version: EVENT_VERSION_V2,
+                event_seq,
                 bounty_id,
                 amount: payout_amount,
                 recipient: contributor,
                 timestamp: env.ledger().timestamp(),
+                client_ref: None,
+                deadline: escrow.deadline,
             },
         );
 
@@ -2274,6 +3060,7 @@ impl BountyEscrowContract {
         if escrow.status != EscrowStatus::Locked {
             return Err(Error::FundsNotLocked);
         }
+        Self::ensure_payout_allowed(&env, &recipient)?;
 
         let now = env.ledger().timestamp();
         let claim_window: u64 = env
@@ -2309,6 +3096,13 @@ impl BountyEscrowContract {
 
     /// Beneficiary calls this to claim their authorized funds within the window.
     ///
+    /// # Errors
+    /// * `EscrowDisputed` - a dispute was opened after the claim was authorized
+    /// * `CommitmentNotFound` - a reveal delay is set, so releases need commit-reveal
+    /// * `ExtendedError::HighValueReleaseRequired` - the escrow is above the high-value threshold
+    /// * `EscrowTooYoung` - the escrow has not reached the minimum lifetime
+    /// * `FundsNotLocked` - the claim was already paid or the escrow is not `Locked`
+    ///
     /// # Reentrancy
     /// Protected by the shared reentrancy guard. Escrow and claim state
     /// are updated *before* the outbound token transfer (CEI pattern).
@@ -2343,17 +3137,29 @@ impl BountyEscrowContract {
             return Err(Error::FundsNotLocked);
         }
 
-        // EFFECTS: update escrow and claim state before external call (CEI)
-        let claim_amount = claim.amount;
-        let claim_recipient = claim.recipient.clone();
-
         let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
+        if escrow.status == EscrowStatus::Disputed {
+            return Err(Error::EscrowDisputed);
+        }
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::ensure_not_high_value(&env, bounty_id);
+        Self::ensure_reveal_not_required(&env)?;
+        Self::ensure_min_lifetime_elapsed(&env, bounty_id)?;
+
+        // EFFECTS: update escrow and claim state before external call (CEI)
+        let claim_amount = claim.amount;
+        let claim_recipient = claim.recipient.clone();
+
         escrow.status = EscrowStatus::Released;
+        Self::record_status_transition(&env, bounty_id, EscrowStatus::Released);
         escrow.remaining_amount = 0;
+        Self::record_released_amount(&env, bounty_id, claim_amount);
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
@@ -2390,6 +3196,9 @@ impl BountyEscrowContract {
 
     /// Delegated claim execution using a capability.
     /// Funds are still transferred to the pending claim recipient.
+    ///
+    /// # Errors
+    /// * Same escrow status checks as `claim`
     pub fn claim_with_capability(
         env: Env,
         bounty_id: u64,
@@ -2421,6 +3230,21 @@ impl BountyEscrowContract {
             return Err(Error::FundsNotLocked);
         }
 
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        if escrow.status == EscrowStatus::Disputed {
+            return Err(Error::EscrowDisputed);
+        }
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::ensure_not_high_value(&env, bounty_id);
+        Self::ensure_reveal_not_required(&env)?;
+        Self::ensure_min_lifetime_elapsed(&env, bounty_id)?;
+
         Self::consume_capability(
             &env,
             &holder,
@@ -2438,12 +3262,9 @@ impl BountyEscrowContract {
             &claim.amount,
         );
 
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
         escrow.status = EscrowStatus::Released;
+        Self::record_status_transition(&env, bounty_id, EscrowStatus::Released);
+        Self::record_released_amount(&env, bounty_id, claim.amount);
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
@@ -2521,57 +3342,261 @@ impl BountyEscrowContract {
             .ok_or(Error::BountyNotFound)
     }
 
-    /// Approve a refund before deadline (admin only).
-    /// This allows early refunds with admin approval.
-    pub fn approve_refund(
-        env: Env,
-        bounty_id: u64,
-        amount: i128,
-        recipient: Address,
-        mode: RefundMode,
-    ) -> Result<(), Error> {
+    /// Freeze an escrow pending dispute resolution (admin only).
+    ///
+    /// Only `Locked` or `PartiallyRefunded` escrows can be disputed. While
+    /// `Disputed`, release and refund calls fail and `get_refund_eligibility`
+    /// reports `can_refund = false`.
+    pub fn open_dispute(env: Env, bounty_id: u64) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
-
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
-
-        let escrow: Escrow = env
+        let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+            .ok_or(Error::BountyNotFound)?;
 
+        if escrow.status == EscrowStatus::Disputed {
+            return Err(Error::EscrowDisputed);
+        }
         if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
         {
             return Err(Error::FundsNotLocked);
         }
 
-        if amount <= 0 || amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
-        }
-
-        let approval = RefundApproval {
-            bounty_id,
-            amount,
-            recipient: recipient.clone(),
-            mode: mode.clone(),
-            approved_by: admin.clone(),
-            approved_at: env.ledger().timestamp(),
-        };
-
         env.storage()
             .persistent()
-            .set(&DataKey::RefundApproval(bounty_id), &approval);
+            .set(&DataKey::DisputedFrom(bounty_id), &escrow.status);
+        escrow.status = EscrowStatus::Disputed;
+        Self::record_status_transition(&env, bounty_id, EscrowStatus::Disputed);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("open")),
+            (bounty_id, admin, env.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Resolve a dispute opened with `open_dispute` (admin only).
+    ///
+    /// The escrow first returns to the status it held before the dispute.
+    /// Then, depending on `outcome`:
+    /// - `ResolvedByPayout` releases the remaining funds to `recipient`.
+    /// - `ResolvedByRefund` refunds the remaining funds to the depositor.
+    /// - `CancelledByAdmin` / `NoActionTaken` leave the escrow as it was.
+    ///
+    /// # Errors
+    /// * `NotDisputed` - the escrow is not under dispute
+    /// * `DisputeRecipientRequired` - `ResolvedByPayout` without a `recipient`
+    /// * `CommitmentNotFound` - `ResolvedByPayout` while a reveal delay is set
+    /// * `ExtendedError::HighValueReleaseRequired` - `ResolvedByPayout` on a high-value escrow;
+    ///   resolve with `NoActionTaken` and use `propose_release` instead
+    /// * Same as `release_funds` / `refund` for the payout and refund outcomes
+    pub fn resolve_dispute(
+        env: Env,
+        bounty_id: u64,
+        outcome: DisputeOutcome,
+        recipient: Option<Address>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(Error::NotDisputed);
+        }
+        if outcome == DisputeOutcome::ResolvedByPayout && recipient.is_none() {
+            return Err(Error::DisputeRecipientRequired);
+        }
+
+        let prior: EscrowStatus = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DisputedFrom(bounty_id))
+            .unwrap_or(EscrowStatus::Locked);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::DisputedFrom(bounty_id));
+        escrow.status = prior.clone();
+        Self::record_status_transition(&env, bounty_id, prior);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("resolve")),
+            (bounty_id, outcome, admin.clone(), env.ledger().timestamp()),
+        );
+
+        match outcome {
+            DisputeOutcome::ResolvedByPayout => {
+                if Self::check_paused(&env, symbol_short!("release")) {
+                    return Err(Error::FundsPaused);
+                }
+                Self::ensure_not_high_value(&env, bounty_id);
+                Self::ensure_reveal_not_required(&env)?;
+                reentrancy_guard::acquire(&env);
+                Self::release_remaining_to(&env, bounty_id, &recipient.unwrap(), None)?;
+                reentrancy_guard::release(&env);
+                Ok(())
+            }
+            DisputeOutcome::ResolvedByRefund => {
+                let approval = RefundApproval {
+                    bounty_id,
+                    amount: escrow.remaining_amount,
+                    recipient: escrow.depositor,
+                    mode: RefundMode::Full,
+                    approved_by: admin,
+                    approved_at: env.ledger().timestamp(),
+                };
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::RefundApproval(bounty_id), &approval);
+                Self::refund_logic(env, bounty_id)
+            }
+            DisputeOutcome::CancelledByAdmin | DisputeOutcome::NoActionTaken => Ok(()),
+        }
+    }
+
+    /// Approve a refund before deadline (admin only).
+    /// This allows early refunds with admin approval.
+    pub fn approve_refund(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        recipient: Address,
+        mode: RefundMode,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if amount <= 0 || amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        let approval = RefundApproval {
+            bounty_id,
+            amount,
+            recipient: recipient.clone(),
+            mode: mode.clone(),
+            approved_by: admin.clone(),
+            approved_at: env.ledger().timestamp(),
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::RefundApproval(bounty_id), &approval);
+
+        Ok(())
+    }
+
+    /// Ask the admin to cancel a bounty before its deadline (depositor only).
+    ///
+    /// Records the request; the depositor can refund early once the admin
+    /// calls `approve_early_refund`.
+    pub fn request_early_refund(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        env.storage().persistent().set(
+            &EarlyRefundKey::Requested(bounty_id),
+            &env.ledger().timestamp(),
+        );
+        Ok(())
+    }
+
+    /// Approve a depositor's early refund request (admin only).
+    ///
+    /// Stores a `RefundMode::Full` approval of the remaining amount to the
+    /// depositor, so a subsequent `refund` succeeds before the deadline.
+    /// Returns `RefundNotApproved` if the depositor has not requested one.
+    pub fn approve_early_refund(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let request_key = EarlyRefundKey::Requested(bounty_id);
+        if !env.storage().persistent().has(&request_key) {
+            return Err(Error::RefundNotApproved);
+        }
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
 
+        let approval = RefundApproval {
+            bounty_id,
+            amount: escrow.remaining_amount,
+            recipient: escrow.depositor,
+            mode: RefundMode::Full,
+            approved_by: admin,
+            approved_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::RefundApproval(bounty_id), &approval);
+        env.storage().persistent().remove(&request_key);
         Ok(())
     }
 
+    /// Whether the depositor has an early refund request awaiting approval.
+    pub fn has_early_refund_request(env: Env, bounty_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .has(&EarlyRefundKey::Requested(bounty_id))
+    }
+
     /// Release a partial amount of the locked funds to the contributor.
     /// Only the admin (backend) can authorize this.
     ///
@@ -2580,6 +3605,10 @@ impl BountyEscrowContract {
     /// - When `remaining_amount` reaches 0 the escrow status is set to Released.
     /// - The bounty stays Locked while any funds remain unreleased.
     ///
+    /// # Errors
+    /// * `ExtendedError::ReleaseLimitReached` - the escrow has used up the
+    ///   cap set by `set_max_releases_per_bounty`
+    ///
     /// # Reentrancy
     /// Protected by the shared reentrancy guard. Escrow state is updated
     /// *before* the outbound token transfer (CEI pattern).
@@ -2588,6 +3617,19 @@ impl BountyEscrowContract {
         bounty_id: u64,
         contributor: Address,
         payout_amount: i128,
+    ) -> Result<(), Error> {
+        Self::ensure_reveal_not_required(&env)?;
+        Self::partial_release_logic(env, bounty_id, contributor, payout_amount, false)
+    }
+
+    /// Shared body of `partial_release` and `reveal_release`.
+    /// `confirm_release` is the only caller that passes `high_value_confirmed`.
+    fn partial_release_logic(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        payout_amount: i128,
+        high_value_confirmed: bool,
     ) -> Result<(), Error> {
         // GUARD: acquire reentrancy lock
         reentrancy_guard::acquire(&env);
@@ -2602,6 +3644,9 @@ impl BountyEscrowContract {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
+        if !high_value_confirmed {
+            Self::ensure_not_high_value(&env, bounty_id);
+        }
 
         let mut escrow: Escrow = env
             .storage()
@@ -2612,6 +3657,8 @@ impl BountyEscrowContract {
         if escrow.status != EscrowStatus::Locked {
             return Err(Error::FundsNotLocked);
         }
+        Self::ensure_min_lifetime_elapsed(&env, bounty_id)?;
+        Self::ensure_payout_allowed(&env, &contributor)?;
 
         // Guard: zero or negative payout makes no sense and would corrupt state
         if payout_amount <= 0 {
@@ -2622,16 +3669,19 @@ impl BountyEscrowContract {
         if payout_amount > escrow.remaining_amount {
             return Err(Error::InsufficientFunds);
         }
+        Self::bump_release_count(&env, bounty_id);
 
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
 
         // Decrement remaining; this is always an exact integer subtraction — no rounding
         escrow.remaining_amount = escrow.remaining_amount.checked_sub(payout_amount).unwrap();
+        Self::record_released_amount(&env, bounty_id, payout_amount);
 
         // Automatically transition to Released once fully paid out
         if escrow.remaining_amount == 0 {
             escrow.status = EscrowStatus::Released;
+            Self::record_status_transition(&env, bounty_id, EscrowStatus::Released);
             let now_ts = env.ledger().timestamp();
             env.storage()
                 .persistent()
@@ -2641,6 +3691,7 @@ impl BountyEscrowContract {
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
 
+        let event_seq = events::next_event_seq(&env);
         // INTERACTION: external token transfer is last (CEI pattern)
         client.transfer(
             &env.current_contract_address(),
@@ -2653,10 +3704,13 @@ impl BountyEscrowContract {
             &env,
             FundsReleased {
                 version: EVENT_VERSION_V2,
+                event_seq,
                 bounty_id,
                 amount: payout_amount,
                 recipient: contributor.clone(),
                 timestamp: env.ledger().timestamp(),
+                client_ref: None,
+                deadline: escrow.deadline,
             },
         );
 
@@ -2665,26 +3719,28 @@ impl BountyEscrowContract {
         Ok(())
     }
 
-    /// Refund funds to the original depositor if the deadline has passed.
-    /// Refunds the full remaining_amount (accounts for any prior partial releases).
+    /// Release funds from one escrow to several contributors at once.
+    /// Only the admin (backend) can authorize this.
     ///
-    /// # Reentrancy
-    /// Protected by the shared reentrancy guard. Escrow state, refund
-    /// history, and approval cleanup are performed *before* the outbound
-    /// token transfer (CEI pattern).
-    pub fn refund(env: Env, bounty_id: u64) -> Result<(), Error> {
-        let res = Self::refund_logic(env.clone(), bounty_id);
-        monitoring::track_operation(
-            &env,
-            symbol_short!("refund"),
-            env.current_contract_address(),
-            res.is_ok(),
-        );
-        res
-    }
-
-    fn refund_logic(env: Env, bounty_id: u64) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("refund")) {
+    /// - `recipients` and `amounts` must be non-empty and the same length.
+    /// - Every amount must be > 0 and their sum must be <= `remaining_amount`.
+    /// - When `token_address` is `Some`, it must equal the escrow token.
+    /// - The bounty stays Locked while funds remain and becomes Released once
+    ///   `remaining_amount` reaches 0, as with `partial_release`.
+    ///
+    /// One `FundsReleased` event is emitted per recipient.
+    ///
+    /// # Errors
+    /// * `ExtendedError::ReleaseLimitReached` - the escrow has used up the
+    ///   cap set by `set_max_releases_per_bounty`
+    pub fn release_funds_split(
+        env: Env,
+        bounty_id: u64,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        token_address: Option<Address>,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
             return Err(Error::FundsPaused);
         }
         if Self::is_escrow_locked(&env, bounty_id) {
@@ -2694,6 +3750,27 @@ impl BountyEscrowContract {
         // GUARD: acquire reentrancy lock
         reentrancy_guard::acquire(&env);
 
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        if let Some(expected) = token_address {
+            if expected != token_addr {
+                return Err(Error::UnexpectedToken);
+            }
+        }
+
+        if recipients.is_empty() {
+            return Err(Error::InvalidBatchSize);
+        }
+        if recipients.len() != amounts.len() {
+            return Err(Error::BatchSizeMismatch);
+        }
+
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
@@ -2704,466 +3781,547 @@ impl BountyEscrowContract {
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
+        if escrow.status != EscrowStatus::Locked {
             return Err(Error::FundsNotLocked);
         }
+        Self::ensure_not_high_value(&env, bounty_id);
+        Self::ensure_reveal_not_required(&env)?;
+        Self::ensure_min_lifetime_elapsed(&env, bounty_id)?;
+        for recipient in recipients.iter() {
+            Self::ensure_payout_allowed(&env, &recipient)?;
+        }
 
-        // Block refund if there is a pending claim (Issue #391 fix)
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
-                .storage()
-                .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
-            if !claim.claimed {
-                return Err(Error::ClaimPending);
+        let mut total: i128 = 0;
+        for amount in amounts.iter() {
+            if amount <= 0 {
+                return Err(Error::InvalidAmount);
             }
+            total = total.checked_add(amount).ok_or(Error::InvalidAmount)?;
         }
-
-        let now = env.ledger().timestamp();
-        let approval_key = DataKey::RefundApproval(bounty_id);
-        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
-
-        // Refund is allowed if:
-        // 1. Deadline has passed (returns full amount to depositor)
-        // 2. An administrative approval exists (can be early, partial, and to custom recipient)
-        if now < escrow.deadline && approval.is_none() {
-            return Err(Error::DeadlineNotPassed);
+        if total > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds);
         }
+        Self::bump_release_count(&env, bounty_id);
 
-        let (refund_amount, refund_to, is_full) = if let Some(app) = approval.clone() {
-            let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
-            (app.amount, app.recipient, full)
-        } else {
-            // Standard refund after deadline
-            (escrow.remaining_amount, escrow.depositor.clone(), true)
-        };
-
-        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
+        // EFFECTS: update state before external calls (CEI)
+        escrow.remaining_amount -= total;
+        Self::record_released_amount(&env, bounty_id, total);
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
+            Self::record_status_transition(&env, bounty_id, EscrowStatus::Released);
+            let now_ts = env.ledger().timestamp();
+            env.storage()
+                .persistent()
+                .set(&DataKey::CompletedAt(bounty_id), &now_ts);
         }
-
-        // EFFECTS: update state before external call (CEI)
         invariants::assert_escrow(&env, &escrow);
-        // Update escrow state: subtract the amount exactly refunded
-        escrow.remaining_amount = escrow.remaining_amount.checked_sub(refund_amount).unwrap();
-        if is_full || escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Refunded;
-        } else {
-            escrow.status = EscrowStatus::PartiallyRefunded;
-        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
 
-        // Add to refund history
-        escrow.refund_history.push_back(RefundRecord {
-            amount: refund_amount,
-            recipient: refund_to.clone(),
-            timestamp: now,
-            mode: if is_full {
-                RefundMode::Full
-            } else {
-                RefundMode::Partial
-            },
-        });
-
-        // Save updated escrow
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
-        if escrow.status == EscrowStatus::Refunded {
-            env.storage()
-                .persistent()
-                .set(&DataKey::CompletedAt(bounty_id), &now);
-        }
-
-        // Remove approval after successful execution
-        if approval.is_some() {
-            env.storage().persistent().remove(&approval_key);
-        }
-
-        // INTERACTION: external token transfer is last
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        // INTERACTION: external token transfers are last
         let client = token::Client::new(&env, &token_addr);
-        client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
-
-        emit_funds_refunded(
-            &env,
-            FundsRefunded {
-                version: EVENT_VERSION_V2,
-                bounty_id,
-                amount: refund_amount,
-                refund_to: refund_to.clone(),
-                timestamp: now,
-            },
-        );
-
-        // INV-2: Verify aggregate balance matches token balance after refund
-        multitoken_invariants::assert_after_disbursement(&env);
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            let event_seq = events::next_event_seq(&env);
+            client.transfer(&env.current_contract_address(), &recipient, &amount);
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    version: EVENT_VERSION_V2,
+                    event_seq,
+                    bounty_id,
+                    amount,
+                    recipient,
+                    timestamp: env.ledger().timestamp(),
+                    client_ref: None,
+                    deadline: escrow.deadline,
+                },
+            );
+        }
 
         // GUARD: release reentrancy lock
         reentrancy_guard::release(&env);
         Ok(())
     }
 
-    /// Delegated refund path using a capability.
-    /// This can be used for short-lived, bounded delegated refunds without granting admin rights.
-    pub fn refund_with_capability(
-        env: Env,
-        bounty_id: u64,
-        amount: i128,
-        holder: Address,
-        capability_id: u64,
-    ) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("refund")) {
-            return Err(Error::FundsPaused);
-        }
-        if Self::is_escrow_locked(&env, bounty_id) {
-            return Err(Error::EscrowLocked);
-        }
-        if amount <= 0 {
-            return Err(Error::InvalidAmount);
+    /// Set the minimum number of seconds an escrow must stay locked before
+    /// it can be released, claimed or paid through a claim ticket (admin
+    /// only). `0` disables the check.
+    pub fn set_min_escrow_lifetime(env: Env, seconds: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::MinEscrowLifetime, &seconds);
+        Ok(())
+    }
+
+    /// Get the minimum escrow lifetime in seconds (default: 0).
+    pub fn get_min_escrow_lifetime(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinEscrowLifetime)
+            .unwrap_or(0)
+    }
+
+    /// Get the timestamp at which an escrow was funded.
+    ///
+    /// Returns 0 for escrows funded before creation times were recorded.
+    pub fn get_escrow_created_at(env: Env, bounty_id: u64) -> Result<u64, Error> {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
-
-        let mut escrow: Escrow = env
+        Ok(env
             .storage()
             .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+            .get(&DataKey::CreatedAt(bounty_id))
+            .unwrap_or(0))
+    }
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
-        }
-        if amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
+    /// Get the cumulative amount released from an escrow across full,
+    /// partial, split, and claim-based releases.
+    ///
+    /// Returns 0 for escrows whose releases predate this counter.
+    pub fn get_escrow_released_amount(env: Env, bounty_id: u64) -> Result<i128, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
         }
-
-        if env
+        Ok(env
             .storage()
             .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
-                .storage()
-                .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
-            if !claim.claimed {
-                return Err(Error::ClaimPending);
-            }
-        }
+            .get(&DataKey::ReleasedAmount(bounty_id))
+            .unwrap_or(0))
+    }
 
-        Self::consume_capability(
-            &env,
-            &holder,
-            capability_id,
-            CapabilityAction::Refund,
-            bounty_id,
-            amount,
-        )?;
+    fn record_released_amount(env: &Env, bounty_id: u64, amount: i128) {
+        let key = DataKey::ReleasedAmount(bounty_id);
+        let released: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&key, &released.checked_add(amount).unwrap());
+    }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        let now = env.ledger().timestamp();
-        let refund_to = escrow.depositor.clone();
+    /// Cap the number of partial releases any one escrow may receive
+    /// (admin only).
+    ///
+    /// Applies to `partial_release`, `release_funds_split` and
+    /// `release_with_capability`; once an escrow has had `max` of them,
+    /// further ones fail with `ExtendedError::ReleaseLimitReached`. Zero
+    /// removes the cap.
+    pub fn set_max_releases_per_bounty(env: Env, max: u32) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        client.transfer(&env.current_contract_address(), &refund_to, &amount);
+        env.storage()
+            .instance()
+            .set(&ReleaseLimitKey::MaxPerBounty, &max);
+        Ok(())
+    }
 
-        escrow.remaining_amount -= amount;
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Refunded;
-        } else {
-            escrow.status = EscrowStatus::PartiallyRefunded;
-        }
+    /// Get the partial release cap per escrow (default: 0, unlimited).
+    pub fn get_max_releases_per_bounty(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&ReleaseLimitKey::MaxPerBounty)
+            .unwrap_or(0)
+    }
 
-        escrow.refund_history.push_back(RefundRecord {
-            amount,
-            recipient: refund_to.clone(),
-            timestamp: now,
-            mode: if escrow.status == EscrowStatus::Refunded {
-                RefundMode::Full
-            } else {
-                RefundMode::Partial
-            },
-        });
+    /// Number of partial releases made from an escrow so far.
+    pub fn get_release_count(env: Env, bounty_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&ReleaseLimitKey::Count(bounty_id))
+            .unwrap_or(0)
+    }
 
+    /// Counts one more partial release, failing once the cap is reached.
+    fn bump_release_count(env: &Env, bounty_id: u64) {
+        let count = Self::get_release_count(env.clone(), bounty_id);
+        let max = Self::get_max_releases_per_bounty(env.clone());
+        if max > 0 && count >= max {
+            panic_with_error!(env, ExtendedError::ReleaseLimitReached);
+        }
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+            .set(&ReleaseLimitKey::Count(bounty_id), &(count + 1));
+    }
 
-        emit_funds_refunded(
-            &env,
-            FundsRefunded {
-                version: EVENT_VERSION_V2,
-                bounty_id,
-                amount,
-                refund_to,
-                timestamp: now,
-            },
-        );
+    /// Backfill creation times for escrows funded before they were recorded
+    /// (admin only).
+    ///
+    /// Sets `created_at = timestamp` for each listed escrow that exists and
+    /// has no recorded creation time; others are left untouched.
+    ///
+    /// # Returns
+    /// Number of escrows backfilled.
+    pub fn migrate_backfill_created_at(
+        env: Env,
+        bounty_ids: Vec<u64>,
+        timestamp: u64,
+    ) -> Result<u32, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        Ok(())
+        let mut backfilled = 0u32;
+        for bounty_id in bounty_ids.iter() {
+            if !env.storage().persistent().has(&DataKey::Escrow(bounty_id))
+                || env
+                    .storage()
+                    .persistent()
+                    .has(&DataKey::CreatedAt(bounty_id))
+            {
+                continue;
+            }
+            env.storage()
+                .persistent()
+                .set(&DataKey::CreatedAt(bounty_id), &timestamp);
+            backfilled += 1;
+        }
+        Ok(backfilled)
     }
 
-    /// view function to get escrow info
-    pub fn get_escrow_info(env: Env, bounty_id: u64) -> Result<Escrow, Error> {
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+    /// Fails with `EscrowTooYoung` while the escrow is younger than the
+    /// configured minimum lifetime. Escrows without a recorded creation time
+    /// are treated as created at 0.
+    fn ensure_min_lifetime_elapsed(env: &Env, bounty_id: u64) -> Result<(), Error> {
+        let min_lifetime = Self::get_min_escrow_lifetime(env.clone());
+        if min_lifetime == 0 {
+            return Ok(());
         }
-        Ok(env
+        let created_at: u64 = env
             .storage()
             .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap())
+            .get(&DataKey::CreatedAt(bounty_id))
+            .unwrap_or(0);
+        if env.ledger().timestamp() < created_at.saturating_add(min_lifetime) {
+            return Err(Error::EscrowTooYoung);
+        }
+        Ok(())
     }
 
-    /// view function to get contract balance of the token
-    pub fn get_balance(env: Env) -> Result<i128, Error> {
-        if !env.storage().instance().has(&DataKey::Token) {
+    /// Set the minimum delay in seconds between `commit_release` and
+    /// `reveal_release` (admin only).
+    ///
+    /// A non-zero delay makes commit-reveal mandatory: direct releases
+    /// (full, partial, split, batch and capability), claims, claim tickets
+    /// and `ResolvedByPayout` dispute resolutions fail with
+    /// `CommitmentNotFound`. The high-value `confirm_release` flow, already
+    /// two-step and time-locked, is unaffected. Zero turns the requirement off.
+    pub fn set_reveal_delay(env: Env, delay: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        Ok(client.balance(&env.current_contract_address()))
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::RevealDelay, &delay);
+        Ok(())
     }
 
-    // =========================================================================
-    // Dry-Run Simulation Entry Points  (Issue #567)
-    //
-    // These are **view-only** functions that replay the validation logic of
-    // lock / release / refund WITHOUT writing any state or performing token
-    // transfers.  They return a `SimulationResult` so callers can preview
-    // the outcome and surface user-facing errors before submitting a real tx.
-    // =========================================================================
+    /// Get the commit-reveal delay in seconds (default: 0).
+    pub fn get_reveal_delay(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RevealDelay)
+            .unwrap_or(0)
+    }
 
-    /// Simulate a `lock_funds` call.
-    ///
-    /// Checks initialisation, duplicate bounty, pause state, and amount policy
-    /// exactly as the real function does, then returns what the resulting
-    /// escrow state would look like.  No auth is required.
-    pub fn simulate_lock(
+    /// Compute the commitment hash expected by `reveal_release`.
+    pub fn compute_release_commitment(
         env: Env,
-        depositor: Address,
-        bounty_id: u64,
+        contributor: Address,
         amount: i128,
-        deadline: u64,
-    ) -> SimulationResult {
-        // --- Checks (mirrors lock_funds validation) ---
+        salt: BytesN<32>,
+    ) -> BytesN<32> {
+        let preimage = (contributor, amount, salt).to_xdr(&env);
+        env.crypto().sha256(&preimage).into()
+    }
 
-        if Self::check_paused(&env, symbol_short!("lock")) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::FundsPaused as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
+    /// First step of a two-step release (admin only).
+    ///
+    /// Stores `hash` as the commitment for `bounty_id`; a later
+    /// `reveal_release` with matching parameters pays out once the reveal
+    /// delay has elapsed. Committing again replaces the previous commitment
+    /// and restarts the delay.
+    pub fn commit_release(env: Env, bounty_id: u64, hash: BytesN<32>) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::NotInitialized as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
         }
 
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::BountyExists as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
-        }
+        env.storage().persistent().set(
+            &DataKey::ReleaseCommitment(bounty_id),
+            &ReleaseCommitment {
+                hash,
+                committed_at: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
 
-        if amount <= 0 {
-            return SimulationResult {
-                success: false,
-                error_code: Error::InvalidAmount as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
-        }
+    /// Second step of a two-step release (admin only).
+    ///
+    /// Pays `amount` to `contributor` if `(contributor, amount, salt)` hashes
+    /// to the stored commitment and the reveal delay has elapsed. Releasing
+    /// the full remaining amount completes the escrow, as with
+    /// `partial_release`. The commitment is consumed on success.
+    pub fn reveal_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        amount: i128,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        let key = DataKey::ReleaseCommitment(bounty_id);
+        let commitment: ReleaseCommitment = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::CommitmentNotFound)?;
 
-        if deadline <= env.ledger().timestamp() {
-            return SimulationResult {
-                success: false,
-                error_code: Error::InvalidDeadline as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
+        let expected =
+            Self::compute_release_commitment(env.clone(), contributor.clone(), amount, salt);
+        if expected != commitment.hash {
+            return Err(Error::CommitmentMismatch);
         }
 
-        // Enforce amount policy if set
-        if let Some((min_amount, max_amount)) = env
-            .storage()
-            .instance()
-            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
-        {
-            if amount < min_amount {
-                return SimulationResult {
-                    success: false,
-                    error_code: Error::AmountBelowMinimum as u32,
-                    amount: 0,
-                    resulting_status: EscrowStatus::Locked,
-                    remaining_amount: 0,
-                };
-            }
-            if amount > max_amount {
-                return SimulationResult {
-                    success: false,
-                    error_code: Error::AmountAboveMaximum as u32,
-                    amount: 0,
-                    resulting_status: EscrowStatus::Locked,
-                    remaining_amount: 0,
-                };
-            }
+        let reveal_at = commitment
+            .committed_at
+            .saturating_add(Self::get_reveal_delay(env.clone()));
+        if env.ledger().timestamp() < reveal_at {
+            return Err(Error::RevealTooEarly);
         }
 
-        // Check depositor has sufficient balance
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::Client::new(&env, &token_addr);
-        let depositor_balance = token_client.balance(&depositor);
-        if depositor_balance < amount {
-            return SimulationResult {
-                success: false,
-                error_code: Error::InsufficientFunds as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
-        }
+        env.storage().persistent().remove(&key);
+        Self::partial_release_logic(env, bounty_id, contributor, amount, false)
+    }
 
-        // --- Would succeed ---
-        SimulationResult {
-            success: true,
-            error_code: 0,
-            amount,
-            resulting_status: EscrowStatus::Locked,
-            remaining_amount: amount,
+    /// Fails with `CommitmentNotFound` while a reveal delay is configured:
+    /// releases must then go through `commit_release` and `reveal_release`.
+    fn ensure_reveal_not_required(env: &Env) -> Result<(), Error> {
+        if Self::get_reveal_delay(env.clone()) > 0 {
+            return Err(Error::CommitmentNotFound);
         }
+        Ok(())
     }
 
-    /// Simulate a `release_funds` call.
+    /// Configure the high-value release flow (admin only).
     ///
-    /// Checks initialisation, existence, pause state, and escrow status
-    /// exactly as the real function does.  Returns the projected released
-    /// state.  No auth is required.
-    pub fn simulate_release(env: Env, bounty_id: u64, _contributor: Address) -> SimulationResult {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::FundsPaused as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
+    /// Escrows with a remaining amount above `high_value_threshold` can no
+    /// longer be paid through any direct release path (full, partial, split,
+    /// batch, capability, reveal, claim, claim ticket or dispute payout); they
+    /// need `propose_release` followed by `confirm_release` at least
+    /// `time_lock_duration` seconds later. A threshold of zero disables the
+    /// flow.
+    pub fn set_high_value_release_config(
+        env: Env,
+        high_value_threshold: i128,
+        time_lock_duration: u64,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::NotInitialized as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
+        if high_value_threshold < 0 {
+            return Err(Error::InvalidAmount);
         }
+        env.storage().instance().set(
+            &HighValueReleaseKey::Config,
+            &HighValueReleaseConfig {
+                high_value_threshold,
+                time_lock_duration,
+            },
+        );
+        Ok(())
+    }
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::BountyNotFound as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
+    /// Get the high-value release configuration (default: disabled).
+    pub fn get_high_value_release_config(env: Env) -> HighValueReleaseConfig {
+        env.storage()
+            .instance()
+            .get(&HighValueReleaseKey::Config)
+            .unwrap_or(HighValueReleaseConfig {
+                high_value_threshold: 0,
+                time_lock_duration: 0,
+            })
+    }
+
+    /// Rejects a direct release when the escrow is above the high-value threshold.
+    fn ensure_not_high_value(env: &Env, bounty_id: u64) {
+        let config = Self::get_high_value_release_config(env.clone());
+        if config.high_value_threshold == 0 {
+            return;
+        }
+        if let Some(escrow) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+        {
+            if escrow.remaining_amount > config.high_value_threshold {
+                panic_with_error!(env, ExtendedError::HighValueReleaseRequired);
+            }
+        }
+    }
+
+    /// First step of a high-value release (admin only).
+    ///
+    /// Records `amount` for `contributor` as pending; `confirm_release` pays
+    /// it out once the configured time lock has elapsed. Proposing again
+    /// replaces the pending release and restarts the time lock.
+    pub fn propose_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
         let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
+            .ok_or(Error::BountyNotFound)?;
         if escrow.status != EscrowStatus::Locked {
-            return SimulationResult {
-                success: false,
-                error_code: Error::FundsNotLocked as u32,
-                amount: 0,
-                resulting_status: escrow.status,
-                remaining_amount: escrow.remaining_amount,
-            };
+            return Err(Error::FundsNotLocked);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if amount > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds);
         }
+        Self::ensure_payout_allowed(&env, &contributor)?;
 
-        // --- Would succeed ---
-        SimulationResult {
-            success: true,
-            error_code: 0,
-            amount: escrow.amount,
-            resulting_status: EscrowStatus::Released,
-            remaining_amount: 0,
+        env.storage().persistent().set(
+            &HighValueReleaseKey::Pending(bounty_id),
+            &PendingRelease {
+                contributor,
+                amount,
+                proposed_at: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Second step of a high-value release (admin only).
+    ///
+    /// Pays the proposed release once `time_lock_duration` has passed since
+    /// it was proposed. Releasing the full remaining amount completes the
+    /// escrow, as with `partial_release`.
+    pub fn confirm_release(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let key = HighValueReleaseKey::Pending(bounty_id);
+        let pending: PendingRelease = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::CommitmentNotFound)?;
+
+        let confirm_at = pending
+            .proposed_at
+            .saturating_add(Self::get_high_value_release_config(env.clone()).time_lock_duration);
+        if env.ledger().timestamp() < confirm_at {
+            return Err(Error::RevealTooEarly);
         }
+
+        env.storage().persistent().remove(&key);
+        Self::partial_release_logic(env, bounty_id, pending.contributor, pending.amount, true)
     }
 
-    /// Simulate a `refund` call.
+    /// Discard a proposed release (admin only).
+    pub fn cancel_release(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let key = HighValueReleaseKey::Pending(bounty_id);
+        if !env.storage().persistent().has(&key) {
+            return Err(Error::CommitmentNotFound);
+        }
+        env.storage().persistent().remove(&key);
+        Ok(())
+    }
+
+    /// Get the release awaiting confirmation for a bounty, if any.
+    pub fn get_pending_release(env: Env, bounty_id: u64) -> Option<PendingRelease> {
+        env.storage()
+            .persistent()
+            .get(&HighValueReleaseKey::Pending(bounty_id))
+    }
+
+    /// Refund funds to the original depositor if the deadline has passed.
+    /// Refunds the full remaining_amount (accounts for any prior partial releases).
     ///
-    /// Checks pause state, existence, escrow status, pending claims,
-    /// deadline, and refund approval exactly as the real function does.
-    /// Returns the projected refund amount and resulting status.
-    /// No auth is required.
-    pub fn simulate_refund(env: Env, bounty_id: u64) -> SimulationResult {
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. Escrow state, refund
+    /// history, and approval cleanup are performed *before* the outbound
+    /// token transfer (CEI pattern).
+    pub fn refund(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let res = Self::refund_logic(env.clone(), bounty_id);
+        monitoring::track_operation(
+            &env,
+            symbol_short!("refund"),
+            env.current_contract_address(),
+            res.is_ok(),
+        );
+        res
+    }
+
+    fn refund_logic(env: Env, bounty_id: u64) -> Result<(), Error> {
         if Self::check_paused(&env, symbol_short!("refund")) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::FundsPaused as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
+            return Err(Error::FundsPaused);
+        }
+        if Self::is_escrow_locked(&env, bounty_id) {
+            return Err(Error::EscrowLocked);
         }
 
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::BountyNotFound as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
+            return Err(Error::BountyNotFound);
         }
 
-        let escrow: Escrow = env
+        let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
 
+        if escrow.status == EscrowStatus::Disputed {
+            return Err(Error::EscrowDisputed);
+        }
         if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
         {
-            return SimulationResult {
-                success: false,
-                error_code: Error::FundsNotLocked as u32,
-                amount: 0,
-                resulting_status: escrow.status,
-                remaining_amount: escrow.remaining_amount,
-            };
+            return Err(Error::FundsNotLocked);
         }
 
-        // Block if there is an active pending claim
+        // Block refund if there is a pending claim (Issue #391 fix)
         if env
             .storage()
             .persistent()
@@ -3175,13 +4333,7 @@ impl BountyEscrowContract {
                 .get(&DataKey::PendingClaim(bounty_id))
                 .unwrap();
             if !claim.claimed {
-                return SimulationResult {
-                    success: false,
-                    error_code: Error::ClaimPending as u32,
-                    amount: 0,
-                    resulting_status: escrow.status,
-                    remaining_amount: escrow.remaining_amount,
-                };
+                return Err(Error::ClaimPending);
             }
         }
 
@@ -3189,420 +4341,1778 @@ impl BountyEscrowContract {
         let approval_key = DataKey::RefundApproval(bounty_id);
         let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
 
+        // Refund is allowed if:
+        // 1. Deadline has passed (returns full amount to depositor)
+        // 2. An administrative approval exists (can be early, partial, and to custom recipient)
         if now < escrow.deadline && approval.is_none() {
-            return SimulationResult {
-                success: false,
-                error_code: Error::DeadlineNotPassed as u32,
-                amount: 0,
-                resulting_status: escrow.status,
-                remaining_amount: escrow.remaining_amount,
-            };
+            return Err(Error::DeadlineNotPassed);
+        }
+        // Within the grace window a deadline refund is admin-only, leaving
+        // time to catch last-minute disputes before it becomes permissionless.
+        if approval.is_none() {
+            let grace_end = escrow
+                .deadline
+                .saturating_add(Self::get_refund_grace_period(env.clone()));
+            if now < grace_end {
+                let admin: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Admin)
+                    .ok_or(Error::NotInitialized)?;
+                admin.require_auth();
+            }
         }
 
-        // Calculate refund parameters (same logic as real refund)
-        let (refund_amount, is_full) = if let Some(app) = approval {
+        let (refund_amount, payees, is_full) = if let Some(app) = approval.clone() {
             let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
-            (app.amount, full)
+            (app.amount, vec![&env, (app.recipient, app.amount)], full)
         } else {
-            (escrow.remaining_amount, true)
+            // Standard refund after deadline, split among contributors
+            let payees = Self::pro_rata_refund_shares(&env, bounty_id, &escrow)?;
+            (escrow.remaining_amount, payees, true)
         };
 
         if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
-            return SimulationResult {
-                success: false,
-                error_code: Error::InvalidAmount as u32,
-                amount: 0,
-                resulting_status: escrow.status,
-                remaining_amount: escrow.remaining_amount,
-            };
+            return Err(Error::InvalidAmount);
         }
 
-        // --- Would succeed ---
-        let new_remaining = escrow.remaining_amount - refund_amount;
-        let new_status = if is_full || new_remaining == 0 {
-            EscrowStatus::Refunded
+        // EFFECTS: update state before external call (CEI)
+        invariants::assert_escrow(&env, &escrow);
+        // Update escrow state: subtract the amount exactly refunded
+        escrow.remaining_amount = escrow.remaining_amount.checked_sub(refund_amount).unwrap();
+        if is_full || escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Refunded;
         } else {
-            EscrowStatus::PartiallyRefunded
-        };
+            escrow.status = EscrowStatus::PartiallyRefunded;
+        }
+        Self::record_status_transition(&env, bounty_id, escrow.status.clone());
 
-        SimulationResult {
-            success: true,
-            error_code: 0,
-            amount: refund_amount,
-            resulting_status: new_status,
-            remaining_amount: new_remaining,
+        // Add to refund history
+        for (recipient, amount) in payees.iter() {
+            Self::append_refund_record(
+                &env,
+                bounty_id,
+                &mut escrow,
+                RefundRecord {
+                    amount,
+                    recipient,
+                    timestamp: now,
+                    mode: if is_full {
+                        RefundMode::Full
+                    } else {
+                        RefundMode::Partial
+                    },
+                },
+            );
         }
-    }
 
-    /// Query escrows with filtering and pagination
-    /// Pass 0 for min values and i128::MAX/u64::MAX for max values to disable those filters
-    pub fn query_escrows_by_status(
-        env: Env,
-        status: EscrowStatus,
-        offset: u32,
-        limit: u32,
-    ) -> Vec<EscrowWithId> {
-        let index: Vec<u64> = env
-            .storage()
+        // Save updated escrow
+        env.storage()
             .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        let mut results = Vec::new(&env);
-        let mut count = 0u32;
-        let mut skipped = 0u32;
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        if escrow.status == EscrowStatus::Refunded {
+            env.storage()
+                .persistent()
+                .set(&DataKey::CompletedAt(bounty_id), &now);
+        }
 
-        for i in 0..index.len() {
-            if count >= limit {
-                break;
-            }
+        // Remove approval after successful execution
+        if approval.is_some() {
+            env.storage().persistent().remove(&approval_key);
+        }
 
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                if escrow.status == status {
-                    if skipped < offset {
-                        skipped = skipped.checked_add(1).unwrap();
-                        continue;
-                    }
-                    results.push_back(EscrowWithId { bounty_id, escrow });
-                    count = count.checked_add(1).unwrap();
-                }
-            }
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        for (refund_to, amount) in payees.iter() {
+            let event_seq = events::next_event_seq(&env);
+            client.transfer(&env.current_contract_address(), &refund_to, &amount);
+
+            emit_funds_refunded(
+                &env,
+                FundsRefunded {
+                    version: EVENT_VERSION_V2,
+                    event_seq,
+                    bounty_id,
+                    amount,
+                    refund_to,
+                    timestamp: now,
+                    deadline: escrow.deadline,
+                },
+            );
         }
-        results
+
+        // INV-2: Verify aggregate balance matches token balance after refund
+        multitoken_invariants::assert_after_disbursement(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
     }
 
-    /// Query escrows with amount range filtering
-    pub fn query_escrows_by_amount(
+    /// Delegated refund path using a capability.
+    /// This can be used for short-lived, bounded delegated refunds without granting admin rights.
+    pub fn refund_with_capability(
         env: Env,
-        min_amount: i128,
-        max_amount: i128,
-        offset: u32,
-        limit: u32,
-    ) -> Vec<EscrowWithId> {
-        let index: Vec<u64> = env
+        bounty_id: u64,
+        amount: i128,
+        holder: Address,
+        capability_id: u64,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
+        }
+        if Self::is_escrow_locked(&env, bounty_id) {
+            return Err(Error::EscrowLocked);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut escrow: Escrow = env
             .storage()
             .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        let mut results = Vec::new(&env);
-        let mut count = 0u32;
-        let mut skipped = 0u32;
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
 
-        for i in 0..index.len() {
-            if count >= limit {
-                break;
-            }
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+        if amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
 
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingClaim(bounty_id))
+        {
+            let claim: ClaimRecord = env
                 .storage()
                 .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                if escrow.amount >= min_amount && escrow.amount <= max_amount {
-                    if skipped < offset {
-                        skipped += 1;
-                        continue;
-                    }
-                    results.push_back(EscrowWithId { bounty_id, escrow });
-                    count = count.checked_add(1).unwrap();
-                }
+                .get(&DataKey::PendingClaim(bounty_id))
+                .unwrap();
+            if !claim.claimed {
+                return Err(Error::ClaimPending);
             }
         }
-        results
-    }
 
-    /// Query escrows with deadline range filtering
-    pub fn query_escrows_by_deadline(
-        env: Env,
-        min_deadline: u64,
-        max_deadline: u64,
-        offset: u32,
-        limit: u32,
-    ) -> Vec<EscrowWithId> {
-        let index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        let mut results = Vec::new(&env);
-        let mut count = 0u32;
-        let mut skipped = 0u32;
+        Self::consume_capability(
+            &env,
+            &holder,
+            capability_id,
+            CapabilityAction::Refund,
+            bounty_id,
+            amount,
+        )?;
 
-        for i in 0..index.len() {
-            if count >= limit {
-                break;
-            }
+        let now = env.ledger().timestamp();
+        let refund_to = escrow.depositor.clone();
 
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                if escrow.deadline >= min_deadline && escrow.deadline <= max_deadline {
-                    if skipped < offset {
-                        skipped += 1;
-                        continue;
-                    }
-                    results.push_back(EscrowWithId { bounty_id, escrow });
-                    count += 1;
-                }
-            }
+        // EFFECTS: update state before external call (CEI)
+        escrow.remaining_amount -= amount;
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Refunded;
+        } else {
+            escrow.status = EscrowStatus::PartiallyRefunded;
         }
-        results
-    }
+        Self::record_status_transition(&env, bounty_id, escrow.status.clone());
 
-    /// Query escrows by depositor
-    pub fn query_escrows_by_depositor(
-        env: Env,
-        depositor: Address,
-        offset: u32,
-        limit: u32,
-    ) -> Vec<EscrowWithId> {
-        let index: Vec<u64> = env
+        let mode = if escrow.status == EscrowStatus::Refunded {
+            RefundMode::Full
+        } else {
+            RefundMode::Partial
+        };
+        Self::append_refund_record(
+            &env,
+            bounty_id,
+            &mut escrow,
+            RefundRecord {
+                amount,
+                recipient: refund_to.clone(),
+                timestamp: now,
+                mode,
+            },
+        );
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let event_seq = events::next_event_seq(&env);
+        client.transfer(&env.current_contract_address(), &refund_to, &amount);
+
+        emit_funds_refunded(
+            &env,
+            FundsRefunded {
+                version: EVENT_VERSION_V2,
+                event_seq,
+                bounty_id,
+                amount,
+                refund_to,
+                timestamp: now,
+                deadline: escrow.deadline,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// view function to check whether an escrow exists for a bounty
+    pub fn escrow_exists(env: Env, bounty_id: u64) -> bool {
+        env.storage().persistent().has(&DataKey::Escrow(bounty_id))
+    }
+
+    /// view function to get escrow info
+    pub fn get_escrow_info(env: Env, bounty_id: u64) -> Result<Escrow, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        Ok(env
             .storage()
             .persistent()
-            .get(&DataKey::DepositorIndex(depositor))
-            .unwrap_or(Vec::new(&env));
-        let mut results = Vec::new(&env);
-        let start = offset.min(index.len());
-        let end = (offset + limit).min(index.len());
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap())
+    }
 
-        for i in start..end {
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                results.push_back(EscrowWithId { bounty_id, escrow });
+    /// view function to get an escrow without its refund history
+    pub fn get_escrow_summary(env: Env, bounty_id: u64) -> Result<EscrowSummary, Error> {
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        Ok(EscrowSummary {
+            bounty_id,
+            depositor: escrow.depositor,
+            amount: escrow.amount,
+            remaining_amount: escrow.remaining_amount,
+            status: escrow.status,
+            deadline: escrow.deadline,
+            token_address,
+        })
+    }
+
+    /// view function to get summaries for several escrows in one call.
+    /// Ids with no escrow are skipped, so the result may be shorter than `ids`.
+    pub fn get_escrow_summaries(env: Env, ids: Vec<u64>) -> Vec<EscrowSummary> {
+        let mut summaries = Vec::new(&env);
+        for bounty_id in ids.iter() {
+            if let Ok(summary) = Self::get_escrow_summary(env.clone(), bounty_id) {
+                summaries.push_back(summary);
             }
         }
-        results
+        summaries
     }
 
-    /// Get aggregate statistics
-    pub fn get_aggregate_stats(env: Env) -> AggregateStats {
-        let index: Vec<u64> = env
+    /// view function to get an escrow's status as a stable code
+    /// (see `traits::StatusQuery`).
+    pub fn get_status(env: Env, id: u64) -> Result<u32, Error> {
+        Ok(Self::get_escrow_info(env, id)?.status.code())
+    }
+
+    /// view function to get the amount still held by an escrow.
+    pub fn get_remaining(env: Env, id: u64) -> Result<i128, Error> {
+        Ok(Self::get_escrow_info(env, id)?.remaining_amount)
+    }
+
+    /// view function to get the nonzero remaining balances of an escrow, per token.
+    ///
+    /// Each contract instance escrows a single token, so the result holds at
+    /// most one `(token, remaining_amount)` entry and is empty once the escrow
+    /// has been fully paid out.
+    pub fn get_remaining_by_token(env: Env, bounty_id: u64) -> Result<Vec<(Address, i128)>, Error> {
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        let mut balances = Vec::new(&env);
+        if escrow.remaining_amount > 0 {
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            balances.push_back((token_addr, escrow.remaining_amount));
+        }
+        Ok(balances)
+    }
+
+    /// view function to get the sum of all remaining balances of an escrow
+    pub fn get_total_remaining(env: Env, bounty_id: u64) -> Result<i128, Error> {
+        let mut total: i128 = 0;
+        for (_, amount) in Self::get_remaining_by_token(env, bounty_id)?.iter() {
+            total = total.checked_add(amount).ok_or(Error::InvalidAmount)?;
+        }
+        Ok(total)
+    }
+
+    /// view function to get contract balance of the token
+    pub fn get_balance(env: Env) -> Result<i128, Error> {
+        if !env.storage().instance().has(&DataKey::Token) {
+            return Err(Error::NotInitialized);
+        }
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        Ok(client.balance(&env.current_contract_address()))
+    }
+
+    /// view function to compare escrow obligations in `token` against the
+    /// contract's live balance of that token.
+    ///
+    /// Obligations are the sum of `remaining_amount` over all indexed escrows.
+    /// Each instance escrows a single token, so obligations are zero for any
+    /// other token.
+    ///
+    /// # Returns
+    /// `(obligations, balance, solvent)` where `solvent` is `balance >= obligations`.
+    pub fn check_solvency(env: Env, token: Address) -> Result<(i128, i128, bool), Error> {
+        let escrow_token: Address = env
             .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        let mut stats = AggregateStats {
-            total_locked: 0,
-            total_released: 0,
-            total_refunded: 0,
-            count_locked: 0,
-            count_released: 0,
-            count_refunded: 0,
-        };
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
 
-        for i in 0..index.len() {
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
+        let mut obligations: i128 = 0;
+        if token == escrow_token {
+            let index: Vec<u64> = env
                 .storage()
                 .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                match escrow.status {
-                    EscrowStatus::Locked => {
-                        stats.total_locked = stats.total_locked.checked_add(escrow.amount).unwrap();
-                        stats.count_locked = stats.count_locked.checked_add(1).unwrap();
-                    }
-                    EscrowStatus::Released => {
-                        stats.total_released =
-                            stats.total_released.checked_add(escrow.amount).unwrap();
-                        stats.count_released = stats.count_released.checked_add(1).unwrap();
-                    }
-                    EscrowStatus::Refunded | EscrowStatus::PartiallyRefunded => {
-                        stats.total_refunded =
-                            stats.total_refunded.checked_add(escrow.amount).unwrap();
-                        stats.count_refunded = stats.count_refunded.checked_add(1).unwrap();
-                    }
-                    EscrowStatus::Template => {
-                        // Template escrows have 0 amount; no aggregate contribution
-                    }
+                .get(&DataKey::EscrowIndex)
+                .unwrap_or(Vec::new(&env));
+            for bounty_id in index.iter() {
+                if let Some(escrow) = env
+                    .storage()
+                    .persistent()
+                    .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+                {
+                    obligations = obligations
+                        .checked_add(escrow.remaining_amount)
+                        .ok_or(Error::InvalidAmount)?;
                 }
             }
         }
-        stats
-    }
 
-    /// Get total count of escrows
-    pub fn get_escrow_count(env: Env) -> u32 {
-        let index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        index.len()
+        let balance = token::Client::new(&env, &token).balance(&env.current_contract_address());
+        Ok((obligations, balance, balance >= obligations))
     }
 
-    /// Set the minimum and maximum allowed lock amount (admin only).
-    ///
-    /// Once set, any call to lock_funds with an amount outside [min_amount, max_amount]
-    /// will be rejected with AmountBelowMinimum or AmountAboveMaximum respectively.
-    /// The policy can be updated at any time by the admin; new limits take effect
-    /// immediately for subsequent lock_funds calls.
+    // =========================================================================
+    // Dry-Run Simulation Entry Points  (Issue #567)
+    //
+    // These are **view-only** functions that replay the validation logic of
+    // lock / release / refund WITHOUT writing any state or performing token
+    // transfers.  They return a `SimulationResult` so callers can preview
+    // the outcome and surface user-facing errors before submitting a real tx.
+    // =========================================================================
+
+    /// Simulate a `lock_funds` call.
     ///
-    /// Passing min_amount == max_amount restricts locking to a single exact value.
-    /// min_amount must not exceed max_amount — the call panics if this invariant
-    /// is violated.
-    pub fn set_amount_policy(
+    /// Checks initialisation, duplicate bounty, pause state, and amount policy
+    /// exactly as the real function does, then returns what the resulting
+    /// escrow state would look like.  No auth is required.
+    pub fn simulate_lock(
         env: Env,
-        caller: Address,
-        min_amount: i128,
-        max_amount: i128,
-    ) -> Result<(), Error> {
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> SimulationResult {
+        // --- Checks (mirrors lock_funds validation) ---
+
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            return SimulationResult {
+                success: false,
+                error_code: Error::FundsPaused as u32,
+                amount: 0,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: 0,
+            };
+        }
+
         if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+            return SimulationResult {
+                success: false,
+                error_code: Error::NotInitialized as u32,
+                amount: 0,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: 0,
+            };
         }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if caller != admin {
-            return Err(Error::Unauthorized);
+
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return SimulationResult {
+                success: false,
+                error_code: Error::BountyExists as u32,
+                amount: 0,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: 0,
+            };
         }
-        admin.require_auth();
 
-        if min_amount > max_amount {
-            panic!("invalid policy: min_amount cannot exceed max_amount");
+        if amount <= 0 {
+            return SimulationResult {
+                success: false,
+                error_code: Error::InvalidAmount as u32,
+                amount: 0,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: 0,
+            };
+        }
+
+        if deadline <= env.ledger().timestamp()
+            || Self::ensure_deadline_within_limits(&env, deadline).is_err()
+        {
+            return SimulationResult {
+                success: false,
+                error_code: Error::InvalidDeadline as u32,
+                amount: 0,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: 0,
+            };
+        }
+
+        // Enforce amount policy if set
+        if let Some((min_amount, max_amount)) = env
+            .storage()
+            .instance()
+            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
+        {
+            if amount < min_amount {
+                return SimulationResult {
+                    success: false,
+                    error_code: Error::AmountBelowMinimum as u32,
+                    amount: 0,
+                    resulting_status: EscrowStatus::Locked,
+                    remaining_amount: 0,
+                };
+            }
+            if amount > max_amount {
+                return SimulationResult {
+                    success: false,
+                    error_code: Error::AmountAboveMaximum as u32,
+                    amount: 0,
+                    resulting_status: EscrowStatus::Locked,
+                    remaining_amount: 0,
+                };
+            }
         }
 
-        // Persist the policy so lock_funds can enforce it on every subsequent call.
+        // Check depositor has sufficient balance
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_addr);
+        let depositor_balance = token_client.balance(&depositor);
+        if depositor_balance < amount {
+            return SimulationResult {
+                success: false,
+                error_code: Error::InsufficientFunds as u32,
+                amount: 0,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: 0,
+            };
+        }
+
+        // --- Would succeed ---
+        SimulationResult {
+            success: true,
+            error_code: 0,
+            amount,
+            resulting_status: EscrowStatus::Locked,
+            remaining_amount: amount,
+        }
+    }
+
+    /// Simulate a `release_funds` call.
+    ///
+    /// Checks initialisation, existence, pause state, and escrow status
+    /// exactly as the real function does.  Returns the projected released
+    /// state.  No auth is required.
+    pub fn simulate_release(env: Env, bounty_id: u64, _contributor: Address) -> SimulationResult {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return SimulationResult {
+                success: false,
+                error_code: Error::FundsPaused as u32,
+                amount: 0,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: 0,
+            };
+        }
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return SimulationResult {
+                success: false,
+                error_code: Error::NotInitialized as u32,
+                amount: 0,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: 0,
+            };
+        }
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return SimulationResult {
+                success: false,
+                error_code: Error::BountyNotFound as u32,
+                amount: 0,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: 0,
+            };
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked {
+            return SimulationResult {
+                success: false,
+                error_code: Error::FundsNotLocked as u32,
+                amount: 0,
+                resulting_status: escrow.status,
+                remaining_amount: escrow.remaining_amount,
+            };
+        }
+
+        // --- Would succeed ---
+        SimulationResult {
+            success: true,
+            error_code: 0,
+            amount: escrow.amount,
+            resulting_status: EscrowStatus::Released,
+            remaining_amount: 0,
+        }
+    }
+
+    /// Simulate a `refund` call.
+    ///
+    /// Checks pause state, existence, escrow status, pending claims,
+    /// deadline, and refund approval exactly as the real function does.
+    /// Returns the projected refund amount and resulting status.
+    /// No auth is required.
+    pub fn simulate_refund(env: Env, bounty_id: u64) -> SimulationResult {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return SimulationResult {
+                success: false,
+                error_code: Error::FundsPaused as u32,
+                amount: 0,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: 0,
+            };
+        }
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return SimulationResult {
+                success: false,
+                error_code: Error::BountyNotFound as u32,
+                amount: 0,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: 0,
+            };
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return SimulationResult {
+                success: false,
+                error_code: Error::FundsNotLocked as u32,
+                amount: 0,
+                resulting_status: escrow.status,
+                remaining_amount: escrow.remaining_amount,
+            };
+        }
+
+        // Block if there is an active pending claim
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::PendingClaim(bounty_id))
+        {
+            let claim: ClaimRecord = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PendingClaim(bounty_id))
+                .unwrap();
+            if !claim.claimed {
+                return SimulationResult {
+                    success: false,
+                    error_code: Error::ClaimPending as u32,
+                    amount: 0,
+                    resulting_status: escrow.status,
+                    remaining_amount: escrow.remaining_amount,
+                };
+            }
+        }
+
+        let now = env.ledger().timestamp();
+        let approval_key = DataKey::RefundApproval(bounty_id);
+        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
+
+        if now < escrow.deadline && approval.is_none() {
+            return SimulationResult {
+                success: false,
+                error_code: Error::DeadlineNotPassed as u32,
+                amount: 0,
+                resulting_status: escrow.status,
+                remaining_amount: escrow.remaining_amount,
+            };
+        }
+
+        // Calculate refund parameters (same logic as real refund)
+        let (refund_amount, is_full) = if let Some(app) = approval {
+            let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
+            (app.amount, full)
+        } else {
+            (escrow.remaining_amount, true)
+        };
+
+        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
+            return SimulationResult {
+                success: false,
+                error_code: Error::InvalidAmount as u32,
+                amount: 0,
+                resulting_status: escrow.status,
+                remaining_amount: escrow.remaining_amount,
+            };
+        }
+
+        // --- Would succeed ---
+        let new_remaining = escrow.remaining_amount - refund_amount;
+        let new_status = if is_full || new_remaining == 0 {
+            EscrowStatus::Refunded
+        } else {
+            EscrowStatus::PartiallyRefunded
+        };
+
+        SimulationResult {
+            success: true,
+            error_code: 0,
+            amount: refund_amount,
+            resulting_status: new_status,
+            remaining_amount: new_remaining,
+        }
+    }
+
+    /// Query escrows with filtering and pagination
+    /// Pass 0 for min values and i128::MAX/u64::MAX for max values to disable those filters
+    pub fn query_escrows_by_status(
+        env: Env,
+        status: EscrowStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowWithId> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut count = 0u32;
+        let mut skipped = 0u32;
+
+        for i in 0..index.len() {
+            if count >= limit {
+                break;
+            }
+
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if escrow.status == status {
+                    if skipped < offset {
+                        skipped = skipped.checked_add(1).unwrap();
+                        continue;
+                    }
+                    results.push_back(EscrowWithId { bounty_id, escrow });
+                    count = count.checked_add(1).unwrap();
+                }
+            }
+        }
+        results
+    }
+
+    /// Query escrows with amount range filtering
+    pub fn query_escrows_by_amount(
+        env: Env,
+        min_amount: i128,
+        max_amount: i128,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowWithId> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut count = 0u32;
+        let mut skipped = 0u32;
+
+        for i in 0..index.len() {
+            if count >= limit {
+                break;
+            }
+
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if escrow.amount >= min_amount && escrow.amount <= max_amount {
+                    if skipped < offset {
+                        skipped += 1;
+                        continue;
+                    }
+                    results.push_back(EscrowWithId { bounty_id, escrow });
+                    count = count.checked_add(1).unwrap();
+                }
+            }
+        }
+        results
+    }
+
+    /// Query escrows with deadline range filtering
+    pub fn query_escrows_by_deadline(
+        env: Env,
+        min_deadline: u64,
+        max_deadline: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowWithId> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut count = 0u32;
+        let mut skipped = 0u32;
+
+        for i in 0..index.len() {
+            if count >= limit {
+                break;
+            }
+
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if escrow.deadline >= min_deadline && escrow.deadline <= max_deadline {
+                    if skipped < offset {
+                        skipped += 1;
+                        continue;
+                    }
+                    results.push_back(EscrowWithId { bounty_id, escrow });
+                    count += 1;
+                }
+            }
+        }
+        results
+    }
+
+    /// Query escrows by depositor
+    ///
+    /// Reads `DataKey::DepositorIndex`, so cost grows with the depositor's own
+    /// bounties rather than with every escrow in the contract.
+    pub fn query_escrows_by_depositor(
+        env: Env,
+        depositor: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowWithId> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DepositorIndex(depositor))
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let start = offset.min(index.len());
+        let end = offset.saturating_add(limit).min(index.len());
+
+        for i in start..end {
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                results.push_back(EscrowWithId { bounty_id, escrow });
+            }
+        }
+        results
+    }
+
+    /// Get funding totals for one depositor's escrows.
+    ///
+    /// Reads `DataKey::DepositorIndex`, so cost grows with the depositor's own
+    /// bounties. Released totals come from the `ReleasedAmount` counter and
+    /// refunded totals from each escrow's refund history, so for every escrow
+    /// released + refunded + still locked equals what was deposited.
+    pub fn get_depositor_summary(env: Env, depositor: Address) -> DepositorSummary {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DepositorIndex(depositor))
+            .unwrap_or(Vec::new(&env));
+        let mut summary = DepositorSummary {
+            active_count: 0,
+            total_locked: 0,
+            total_refunded: 0,
+            total_released: 0,
+        };
+
+        for bounty_id in index.iter() {
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if escrow.status == EscrowStatus::Template {
+                    continue;
+                }
+                if Self::is_active_status(&escrow.status) {
+                    summary.active_count += 1;
+                }
+                // Fully released escrows that predate the counter fall back
+                // to their original amount.
+                let released = env
+                    .storage()
+                    .persistent()
+                    .get::<DataKey, i128>(&DataKey::ReleasedAmount(bounty_id))
+                    .unwrap_or(if escrow.status == EscrowStatus::Released {
+                        escrow.amount
+                    } else {
+                        0
+                    });
+                summary.total_locked = summary
+                    .total_locked
+                    .checked_add(escrow.remaining_amount)
+                    .unwrap();
+                summary.total_released = summary.total_released.checked_add(released).unwrap();
+                summary.total_refunded = summary
+                    .total_refunded
+                    .checked_add(Self::refunded_total(&env, bounty_id, &escrow))
+                    .unwrap();
+            }
+        }
+        summary
+    }
+
+    /// Sum of every refund recorded for an escrow, archived pages included.
+    fn refunded_total(env: &Env, bounty_id: u64, escrow: &Escrow) -> i128 {
+        let mut total: i128 = 0;
+        for record in escrow.refund_history.iter() {
+            total = total.checked_add(record.amount).unwrap();
+        }
+        let archived: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RefundArchiveLen(bounty_id))
+            .unwrap_or(0);
+        let pages = archived.div_ceil(REFUND_ARCHIVE_PAGE_SIZE);
+        for page_no in 0..pages {
+            let page: Vec<RefundRecord> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::RefundArchive(bounty_id, page_no))
+                .unwrap_or(Vec::new(env));
+            for record in page.iter() {
+                total = total.checked_add(record.amount).unwrap();
+            }
+        }
+        total
+    }
+
+    /// Get aggregate statistics
+    ///
+    /// Scans every escrow, so cost grows with the registry; use
+    /// `get_aggregate_stats_bounded` to cap the work per call.
+    pub fn get_aggregate_stats(env: Env) -> AggregateStats {
+        Self::get_aggregate_stats_bounded(env, u32::MAX).0
+    }
+
+    /// Aggregate statistics over at most the first `max_scan` escrows.
+    ///
+    /// Returns the stats and whether they are partial (`true` when the
+    /// registry holds more than `max_scan` escrows).
+    pub fn get_aggregate_stats_bounded(env: Env, max_scan: u32) -> (AggregateStats, bool) {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut stats = AggregateStats {
+            total_locked: 0,
+            total_released: 0,
+            total_refunded: 0,
+            count_locked: 0,
+            count_released: 0,
+            count_refunded: 0,
+        };
+        let truncated = index.len() > max_scan;
+
+        for i in 0..index.len().min(max_scan) {
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                // Released totals include partial releases from escrows that
+                // are still Locked. Fully released escrows that predate the
+                // counter fall back to their original amount.
+                let released = env
+                    .storage()
+                    .persistent()
+                    .get::<DataKey, i128>(&DataKey::ReleasedAmount(bounty_id))
+                    .unwrap_or(if escrow.status == EscrowStatus::Released {
+                        escrow.amount
+                    } else {
+                        0
+                    });
+                stats.total_released = stats.total_released.checked_add(released).unwrap();
+                // Locked and refunded totals follow what is actually still held
+                // and what was actually paid back, so partial releases and
+                // partial refunds are never counted twice.
+                stats.total_locked = stats
+                    .total_locked
+                    .checked_add(escrow.remaining_amount)
+                    .unwrap();
+                stats.total_refunded = stats
+                    .total_refunded
+                    .checked_add(Self::refunded_total(&env, bounty_id, &escrow))
+                    .unwrap();
+
+                match escrow.status {
+                    EscrowStatus::Locked | EscrowStatus::Disputed => {
+                        stats.count_locked = stats.count_locked.checked_add(1).unwrap();
+                    }
+                    EscrowStatus::Released => {
+                        stats.count_released = stats.count_released.checked_add(1).unwrap();
+                    }
+                    EscrowStatus::Refunded | EscrowStatus::PartiallyRefunded => {
+                        stats.count_refunded = stats.count_refunded.checked_add(1).unwrap();
+                    }
+                    EscrowStatus::Template => {
+                        // Template escrows have 0 amount; no aggregate contribution
+                    }
+                }
+            }
+        }
+        (stats, truncated)
+    }
+
+    /// Get total count of escrows
+    pub fn get_escrow_count(env: Env) -> u32 {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        index.len()
+    }
+
+    /// Set the minimum and maximum allowed lock amount (admin only).
+    ///
+    /// Once set, any call to lock_funds with an amount outside [min_amount, max_amount]
+    /// will be rejected with AmountBelowMinimum or AmountAboveMaximum respectively.
+    /// The policy can be updated at any time by the admin; new limits take effect
+    /// immediately for subsequent lock_funds calls.
+    ///
+    /// Passing min_amount == max_amount restricts locking to a single exact value.
+    /// min_amount must not exceed max_amount — the call panics if this invariant
+    /// is violated.
+    pub fn set_amount_policy(
+        env: Env,
+        caller: Address,
+        min_amount: i128,
+        max_amount: i128,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+
+        if min_amount > max_amount {
+            panic!("invalid policy: min_amount cannot exceed max_amount");
+        }
+
+        // Persist the policy so lock_funds can enforce it on every subsequent call.
+        env.storage()
+            .instance()
+            .set(&DataKey::AmountPolicy, &(min_amount, max_amount));
+
+        Ok(())
+    }
+
+    /// Set the lock amount policy in whole-token units (admin only).
+    ///
+    /// `min_whole` and `max_whole` are scaled by `10^decimals` of the escrow
+    /// token before being stored, so a minimum of 1 on a 7-decimal token
+    /// becomes 10_000_000 base units. Returns `InvalidAmount` if scaling
+    /// overflows.
+    pub fn set_amount_policy_whole_tokens(
+        env: Env,
+        caller: Address,
+        min_whole: i128,
+        max_whole: i128,
+    ) -> Result<(), Error> {
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        let unit = 10_i128
+            .checked_pow(Self::token_decimals(&env, &token))
+            .ok_or(Error::InvalidAmount)?;
+        let min_amount = min_whole.checked_mul(unit).ok_or(Error::InvalidAmount)?;
+        let max_amount = max_whole.checked_mul(unit).ok_or(Error::InvalidAmount)?;
+        Self::set_amount_policy(env, caller, min_amount, max_amount)
+    }
+
+    /// Bound how far ahead of the lock time a new escrow's deadline may be
+    /// (admin only).
+    ///
+    /// `lock_funds` rejects a deadline less than `min_duration` or more than
+    /// `max_duration` seconds from now with `InvalidDeadline`. Either bound
+    /// may be 0 to leave that side unbounded. Lock amounts are bounded
+    /// separately by `set_amount_policy`.
+    pub fn set_deadline_limits(
+        env: Env,
+        min_duration: u64,
+        max_duration: u64,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if max_duration != 0 && min_duration > max_duration {
+            return Err(Error::InvalidDeadline);
+        }
+        env.storage()
+            .instance()
+            .set(&DeadlineLimitKey::Limits, &(min_duration, max_duration));
+        Ok(())
+    }
+
+    /// Get the deadline limits as `(min_duration, max_duration)`
+    /// (default: `(0, 0)`, unbounded).
+    pub fn get_deadline_limits(env: Env) -> (u64, u64) {
+        env.storage()
+            .instance()
+            .get(&DeadlineLimitKey::Limits)
+            .unwrap_or((0, 0))
+    }
+
+    /// Rejects a deadline outside the configured duration bounds.
+    fn ensure_deadline_within_limits(env: &Env, deadline: u64) -> Result<(), Error> {
+        let (min_duration, max_duration) = Self::get_deadline_limits(env.clone());
+        let duration = deadline.saturating_sub(env.ledger().timestamp());
+        if min_duration != 0 && duration < min_duration {
+            return Err(Error::InvalidDeadline);
+        }
+        if max_duration != 0 && duration > max_duration {
+            return Err(Error::InvalidDeadline);
+        }
+        Ok(())
+    }
+
+    /// Decimals of `token`, cached in instance storage after the first
+    /// cross-contract read.
+    fn token_decimals(env: &Env, token: &Address) -> u32 {
+        let key = TokenInfoKey::Decimals(token.clone());
+        if let Some(decimals) = env.storage().instance().get(&key) {
+            return decimals;
+        }
+        let decimals = token::Client::new(env, token).decimals();
+        env.storage().instance().set(&key, &decimals);
+        decimals
+    }
+
+    /// Get escrow IDs by status
+    pub fn get_escrow_ids_by_status(
+        env: Env,
+        status: EscrowStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut count = 0u32;
+        let mut skipped = 0u32;
+
+        for i in 0..index.len() {
+            if count >= limit {
+                break;
+            }
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if escrow.status == status {
+                    if skipped < offset {
+                        skipped += 1;
+                        continue;
+                    }
+                    results.push_back(bounty_id);
+                    count += 1;
+                }
+            }
+        }
+        results
+    }
+
+    /// Get IDs of escrows a keeper can refund: deadline reached and status
+    /// `Locked` or `PartiallyRefunded`.
+    ///
+    /// `offset` and `limit` page over the escrow index rather than over
+    /// matches, so each call reads at most `limit` escrows; a page may hold
+    /// fewer than `limit` IDs even when later entries match.
+    pub fn get_expirable_bounties(env: Env, offset: u32, limit: u32) -> Vec<u64> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let now = env.ledger().timestamp();
+        let end = offset.saturating_add(limit).min(index.len());
+        let mut results = Vec::new(&env);
+
+        for i in offset..end {
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                let refundable = escrow.status == EscrowStatus::Locked
+                    || escrow.status == EscrowStatus::PartiallyRefunded;
+                if refundable && now >= escrow.deadline {
+                    results.push_back(bounty_id);
+                }
+            }
+        }
+        results
+    }
+
+    pub fn set_anti_abuse_admin(env: Env, admin: Address) -> Result<(), Error> {
+        let current: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        current.require_auth();
+        anti_abuse::set_admin(&env, admin);
+        Ok(())
+    }
+
+    pub fn get_anti_abuse_admin(env: Env) -> Option<Address> {
+        anti_abuse::get_admin(&env)
+    }
+
+    /// Set whitelist status for an address (admin only). Named to avoid SDK client method conflict.
+    pub fn set_whitelist_entry(
+        env: Env,
+        whitelisted_address: Address,
+        whitelisted: bool,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        anti_abuse::set_whitelist(&env, whitelisted_address, whitelisted);
+        Ok(())
+    }
+
+    /// Update anti-abuse config (rate limit window, max operations per window, cooldown). Admin only.
+    pub fn update_anti_abuse_config(
+        env: Env,
+        window_size: u64,
+        max_operations: u32,
+        cooldown_period: u64,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        let config = anti_abuse::AntiAbuseConfig {
+            window_size,
+            max_operations,
+            cooldown_period,
+        };
+        anti_abuse::set_config(&env, config);
+        Ok(())
+    }
+
+    /// Get current anti-abuse config (rate limit and cooldown).
+    pub fn get_anti_abuse_config(env: Env) -> AntiAbuseConfigView {
+        let c = anti_abuse::get_config(&env);
+        AntiAbuseConfigView {
+            window_size: c.window_size,
+            max_operations: c.max_operations,
+            cooldown_period: c.cooldown_period,
+        }
+    }
+
+    /// Retrieves the refund history for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok(Vec<RefundRecord>)` - The refund history
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_refund_history(env: Env, bounty_id: u64) -> Result<Vec<RefundRecord>, Error> {
+        Self::get_refund_history_page(env, bounty_id, 0, u32::MAX)
+    }
+
+    /// Retrieves a page of the full refund history, oldest first.
+    ///
+    /// Records archived out of the escrow come first, followed by those
+    /// still held inline in `Escrow::refund_history`.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<RefundRecord>)` - Up to `limit` records starting at `offset`
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_refund_history_page(
+        env: Env,
+        bounty_id: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<RefundRecord>, Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let archived: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RefundArchiveLen(bounty_id))
+            .unwrap_or(0);
+
+        let mut results = Vec::new(&env);
+        let mut position = offset;
+        let mut page_no = u32::MAX;
+        let mut page: Vec<RefundRecord> = Vec::new(&env);
+        while results.len() < limit && position < archived {
+            if position / REFUND_ARCHIVE_PAGE_SIZE != page_no {
+                page_no = position / REFUND_ARCHIVE_PAGE_SIZE;
+                page = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::RefundArchive(bounty_id, page_no))
+                    .unwrap_or(Vec::new(&env));
+            }
+            results.push_back(page.get(position % REFUND_ARCHIVE_PAGE_SIZE).unwrap());
+            position += 1;
+        }
+
+        let mut inline_index = position.saturating_sub(archived);
+        while results.len() < limit && inline_index < escrow.refund_history.len() {
+            results.push_back(escrow.refund_history.get(inline_index).unwrap());
+            inline_index += 1;
+        }
+        Ok(results)
+    }
+
+    /// Returns the total number of refund records for a bounty, archived
+    /// and inline, for sizing calls to `get_refund_history_page`.
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - The number of refund records
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_refund_history_count(env: Env, bounty_id: u64) -> Result<u32, Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let archived: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RefundArchiveLen(bounty_id))
+            .unwrap_or(0);
+        Ok(archived + escrow.refund_history.len())
+    }
+
+    /// Set how many refund records are kept inline in an escrow before older
+    /// ones are moved to archive pages (admin only).
+    pub fn set_refund_history_cap(env: Env, cap: u32) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        if cap == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::RefundHistoryCap, &cap);
+        Ok(())
+    }
+
+    /// Allow or forbid top-ups from addresses other than the depositor
+    /// (admin only).
+    pub fn set_allow_third_party_topup(env: Env, allowed: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::AllowThirdPartyTopUp, &allowed);
+        Ok(())
+    }
+
+    /// Set the maximum number of items per batch lock or release call
+    /// (admin only). Must be between 1 and `MAX_BATCH_SIZE_LIMIT`.
+    pub fn set_max_batch_size(env: Env, size: u32) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if size == 0 || size > MAX_BATCH_SIZE_LIMIT {
+            return Err(Error::InvalidBatchSize);
+        }
+        env.storage().instance().set(&DataKey::MaxBatchSize, &size);
+        Ok(())
+    }
+
+    /// Restrict releases to allowlisted recipients, or lift the restriction
+    /// (admin only). Independent of anti-abuse whitelisting.
+    pub fn set_payout_allowlist_enabled(env: Env, enabled: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&PayoutAllowlistKey::Enabled, &enabled);
+        Ok(())
+    }
+
+    /// Whether releases are restricted to allowlisted recipients (default: false).
+    pub fn payout_allowlist_enabled(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&PayoutAllowlistKey::Enabled)
+            .unwrap_or(false)
+    }
+
+    /// Approve `address` as a release recipient (admin only).
+    pub fn add_payout_address(env: Env, address: Address) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&PayoutAllowlistKey::Address(address), &true);
+        Ok(())
+    }
+
+    /// Remove `address` from the release allowlist (admin only).
+    pub fn remove_payout_address(env: Env, address: Address) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&PayoutAllowlistKey::Address(address));
+        Ok(())
+    }
+
+    /// Whether `address` is on the release allowlist.
+    pub fn is_payout_address(env: Env, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&PayoutAllowlistKey::Address(address))
+            .unwrap_or(false)
+    }
+
+    fn ensure_payout_allowed(env: &Env, recipient: &Address) -> Result<(), Error> {
+        if Self::payout_allowlist_enabled(env.clone())
+            && !Self::is_payout_address(env.clone(), recipient.clone())
+        {
+            return Err(Error::RecipientNotAllowed);
+        }
+        Ok(())
+    }
+
+    /// Set how long after an escrow's deadline a deadline refund stays
+    /// admin-only (admin only). Zero makes refunds permissionless as soon as
+    /// the deadline passes.
+    pub fn set_refund_grace_period(env: Env, period: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RefundGracePeriod, &period);
+        Ok(())
+    }
+
+    /// Seconds after the deadline during which only the admin may refund
+    /// (default: 0).
+    pub fn get_refund_grace_period(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RefundGracePeriod)
+            .unwrap_or(0)
+    }
+
+    /// Maximum items per batch call (default: `DEFAULT_MAX_BATCH_SIZE`).
+    pub fn get_max_batch_size(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxBatchSize)
+            .unwrap_or(DEFAULT_MAX_BATCH_SIZE)
+    }
+
+    /// Whether third-party top-ups are allowed (default: false).
+    pub fn get_allow_third_party_topup(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::AllowThirdPartyTopUp)
+            .unwrap_or(false)
+    }
+
+    /// Get how much each address has contributed to an escrow.
+    ///
+    /// Escrows that were never topped up report the depositor as the only
+    /// contributor of the full `amount`.
+    pub fn get_contributors(env: Env, bounty_id: u64) -> Result<Map<Address, i128>, Error> {
+        if let Some(contributors) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contributors(bounty_id))
+        {
+            return Ok(contributors);
+        }
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let mut contributors = Map::new(&env);
+        contributors.set(escrow.depositor, escrow.amount);
+        Ok(contributors)
+    }
+
+    /// Splits the escrow's remaining amount among its contributors in
+    /// proportion to what each put in. Rounding dust goes to the last
+    /// contributor so the shares always sum to `remaining_amount`.
+    fn pro_rata_refund_shares(
+        env: &Env,
+        bounty_id: u64,
+        escrow: &Escrow,
+    ) -> Result<Vec<(Address, i128)>, Error> {
+        let contributors: Map<Address, i128> = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contributors(bounty_id))
+        {
+            Some(contributors) => contributors,
+            None => {
+                return Ok(vec![
+                    env,
+                    (escrow.depositor.clone(), escrow.remaining_amount),
+                ])
+            }
+        };
+        let mut total: i128 = 0;
+        for (_, amount) in contributors.iter() {
+            total = total.checked_add(amount).ok_or(Error::InvalidAmount)?;
+        }
+
+        let mut shares = Vec::new(env);
+        let mut allocated: i128 = 0;
+        let last = contributors.len().saturating_sub(1);
+        for (i, (contributor, amount)) in contributors.iter().enumerate() {
+            let share = if i as u32 == last {
+                escrow.remaining_amount - allocated
+            } else {
+                escrow
+                    .remaining_amount
+                    .checked_mul(amount)
+                    .ok_or(Error::InvalidAmount)?
+                    / total
+            };
+            allocated += share;
+            if share > 0 {
+                shares.push_back((contributor, share));
+            }
+        }
+        Ok(shares)
+    }
+
+    /// Get the inline refund history cap (default: `DEFAULT_REFUND_HISTORY_CAP`).
+    pub fn get_refund_history_cap(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RefundHistoryCap)
+            .unwrap_or(DEFAULT_REFUND_HISTORY_CAP)
+    }
+
+    /// Appends a refund record to the escrow, moving the oldest inline
+    /// records to archive pages once the inline cap is exceeded.
+    fn append_refund_record(env: &Env, bounty_id: u64, escrow: &mut Escrow, record: RefundRecord) {
+        escrow.refund_history.push_back(record);
+
+        let cap = Self::get_refund_history_cap(env.clone());
+        while escrow.refund_history.len() > cap {
+            let oldest = escrow.refund_history.pop_front().unwrap();
+
+            let archived: u32 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::RefundArchiveLen(bounty_id))
+                .unwrap_or(0);
+            let page_key = DataKey::RefundArchive(bounty_id, archived / REFUND_ARCHIVE_PAGE_SIZE);
+            let mut page: Vec<RefundRecord> = env
+                .storage()
+                .persistent()
+                .get(&page_key)
+                .unwrap_or(Vec::new(env));
+            page.push_back(oldest);
+            env.storage().persistent().set(&page_key, &page);
+            env.storage()
+                .persistent()
+                .set(&DataKey::RefundArchiveLen(bounty_id), &(archived + 1));
+        }
+    }
+
+    /// Get the recorded status transitions of an escrow, oldest first.
+    ///
+    /// Each entry is `(status, timestamp)`; at most `STATUS_HISTORY_CAP`
+    /// entries are kept.
+    pub fn get_status_history(env: Env, bounty_id: u64) -> Result<Vec<(EscrowStatus, u64)>, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKey::StatusHistory(bounty_id))
+            .unwrap_or(Vec::new(&env)))
+    }
+
+    /// Appends a status transition to the escrow's history, dropping the
+    /// oldest entry once `STATUS_HISTORY_CAP` is exceeded.
+    fn record_status_transition(env: &Env, bounty_id: u64, status: EscrowStatus) {
+        let key = DataKey::StatusHistory(bounty_id);
+        let mut history: Vec<(EscrowStatus, u64)> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        let previous = history.last().map(|(last, _)| last);
+        if previous.as_ref() == Some(&status) {
+            return;
+        }
+        // Count each escrow once on entering a funded status and once on
+        // leaving for a terminal one, however many transitions it goes through.
+        let was_active = previous.as_ref().is_some_and(Self::is_active_status);
+        let is_active = Self::is_active_status(&status);
+        if was_active != is_active {
+            let count: u64 = env
+                .storage()
+                .instance()
+                .get(&DataKey::ActiveEscrowCount)
+                .unwrap_or(0);
+            let count = if is_active {
+                count + 1
+            } else {
+                count.saturating_sub(1)
+            };
+            env.storage()
+                .instance()
+                .set(&DataKey::ActiveEscrowCount, &count);
+        }
+        if let Some(previous) = previous.as_ref() {
+            Self::remove_from_status_index(env, previous.code(), bounty_id);
+        }
+        Self::add_to_status_index(env, status.code(), bounty_id);
+        history.push_back((status, env.ledger().timestamp()));
+        while history.len() > STATUS_HISTORY_CAP {
+            history.pop_front();
+        }
+        env.storage().persistent().set(&key, &history);
+    }
+
+    fn status_index_page(env: &Env, code: u32, page_no: u32) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&StatusIndexKey::Page(code, page_no))
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn add_to_status_index(env: &Env, code: u32, bounty_id: u64) {
+        let len: u32 = env
+            .storage()
+            .persistent()
+            .get(&StatusIndexKey::Len(code))
+            .unwrap_or(0);
+        let page_no = len / STATUS_INDEX_PAGE_SIZE;
+        let mut page = Self::status_index_page(env, code, page_no);
+        page.push_back(bounty_id);
+        env.storage()
+            .persistent()
+            .set(&StatusIndexKey::Page(code, page_no), &page);
+        env.storage()
+            .persistent()
+            .set(&StatusIndexKey::Position(bounty_id), &len);
+        env.storage()
+            .persistent()
+            .set(&StatusIndexKey::Len(code), &(len + 1));
+    }
+
+    /// Removes `bounty_id` from the status list by moving the last entry
+    /// into its slot, so the list stays dense without shifting pages.
+    fn remove_from_status_index(env: &Env, code: u32, bounty_id: u64) {
+        let position: Option<u32> = env
+            .storage()
+            .persistent()
+            .get(&StatusIndexKey::Position(bounty_id));
+        let len: u32 = env
+            .storage()
+            .persistent()
+            .get(&StatusIndexKey::Len(code))
+            .unwrap_or(0);
+        let position = match position {
+            Some(position) if position < len => position,
+            _ => return,
+        };
+
+        let last = len - 1;
+        let last_page_no = last / STATUS_INDEX_PAGE_SIZE;
+        let mut last_page = Self::status_index_page(env, code, last_page_no);
+        let moved = last_page.pop_back().unwrap();
+        if last_page.is_empty() {
+            env.storage()
+                .persistent()
+                .remove(&StatusIndexKey::Page(code, last_page_no));
+        } else {
+            env.storage()
+                .persistent()
+                .set(&StatusIndexKey::Page(code, last_page_no), &last_page);
+        }
+
+        if position != last {
+            let page_no = position / STATUS_INDEX_PAGE_SIZE;
+            let mut page = Self::status_index_page(env, code, page_no);
+            page.set(position % STATUS_INDEX_PAGE_SIZE, moved);
+            env.storage()
+                .persistent()
+                .set(&StatusIndexKey::Page(code, page_no), &page);
+            env.storage()
+                .persistent()
+                .set(&StatusIndexKey::Position(moved), &position);
+        }
+        env.storage()
+            .persistent()
+            .remove(&StatusIndexKey::Position(bounty_id));
+        env.storage()
+            .persistent()
+            .set(&StatusIndexKey::Len(code), &last);
+    }
+
+    fn is_active_status(status: &EscrowStatus) -> bool {
+        matches!(
+            status,
+            EscrowStatus::Locked | EscrowStatus::PartiallyRefunded | EscrowStatus::Disputed
+        )
+    }
+
+    /// Number of escrows still holding funds (Locked, PartiallyRefunded or
+    /// Disputed), maintained incrementally on every status transition.
+    pub fn get_active_escrow_count(env: Env) -> u64 {
         env.storage()
             .instance()
-            .set(&DataKey::AmountPolicy, &(min_amount, max_amount));
-
-        Ok(())
+            .get(&DataKey::ActiveEscrowCount)
+            .unwrap_or(0)
     }
 
-    /// Get escrow IDs by status
-    pub fn get_escrow_ids_by_status(
+    /// Paginated escrows currently in `status`, given as a
+    /// `traits::StatusQuery` status code. Reads the paged index kept up to
+    /// date by every status transition, loading only the pages the window
+    /// covers. Removing an escrow from a status moves the last entry into
+    /// its slot, so order is not stable across transitions. Unknown codes
+    /// yield an empty list.
+    pub fn get_escrows_by_status(
         env: Env,
-        status: EscrowStatus,
+        status: u32,
         offset: u32,
         limit: u32,
-    ) -> Vec<u64> {
-        let index: Vec<u64> = env
+    ) -> Vec<EscrowWithId> {
+        let len: u32 = env
             .storage()
             .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
+            .get(&StatusIndexKey::Len(status))
+            .unwrap_or(0);
         let mut results = Vec::new(&env);
-        let mut count = 0u32;
-        let mut skipped = 0u32;
-
-        for i in 0..index.len() {
-            if count >= limit {
-                break;
+        let end = offset.saturating_add(limit).min(len);
+        let mut page_no = u32::MAX;
+        let mut page: Vec<u64> = Vec::new(&env);
+        for position in offset..end {
+            if position / STATUS_INDEX_PAGE_SIZE != page_no {
+                page_no = position / STATUS_INDEX_PAGE_SIZE;
+                page = Self::status_index_page(&env, status, page_no);
             }
-            let bounty_id = index.get(i).unwrap();
+            let bounty_id = page.get(position % STATUS_INDEX_PAGE_SIZE).unwrap();
             if let Some(escrow) = env
                 .storage()
                 .persistent()
                 .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
             {
-                if escrow.status == status {
-                    if skipped < offset {
-                        skipped += 1;
-                        continue;
-                    }
-                    results.push_back(bounty_id);
-                    count += 1;
-                }
+                results.push_back(EscrowWithId { bounty_id, escrow });
             }
         }
         results
     }
 
-    pub fn set_anti_abuse_admin(env: Env, admin: Address) -> Result<(), Error> {
-        let current: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInitialized)?;
-        current.require_auth();
-        anti_abuse::set_admin(&env, admin);
-        Ok(())
-    }
-
-    pub fn get_anti_abuse_admin(env: Env) -> Option<Address> {
-        anti_abuse::get_admin(&env)
-    }
-
-    /// Set whitelist status for an address (admin only). Named to avoid SDK client method conflict.
-    pub fn set_whitelist_entry(
-        env: Env,
-        whitelisted_address: Address,
-        whitelisted: bool,
-    ) -> Result<(), Error> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInitialized)?;
-        admin.require_auth();
-        anti_abuse::set_whitelist(&env, whitelisted_address, whitelisted);
-        Ok(())
-    }
-
-    /// Update anti-abuse config (rate limit window, max operations per window, cooldown). Admin only.
-    pub fn update_anti_abuse_config(
-        env: Env,
-        window_size: u64,
-        max_operations: u32,
-        cooldown_period: u64,
-    ) -> Result<(), Error> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInitialized)?;
-        admin.require_auth();
-        let config = anti_abuse::AntiAbuseConfig {
-            window_size,
-            max_operations,
-            cooldown_period,
-        };
-        anti_abuse::set_config(&env, config);
-        Ok(())
-    }
-
-    /// Get current anti-abuse config (rate limit and cooldown).
-    pub fn get_anti_abuse_config(env: Env) -> AntiAbuseConfigView {
-        let c = anti_abuse::get_config(&env);
-        AntiAbuseConfigView {
-            window_size: c.window_size,
-            max_operations: c.max_operations,
-            cooldown_period: c.cooldown_period,
-        }
-    }
-
-    /// Retrieves the refund history for a specific bounty.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to query
-    ///
-    /// # Returns
-    /// * `Ok(Vec<RefundRecord>)` - The refund history
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    pub fn get_refund_history(env: Env, bounty_id: u64) -> Result<Vec<RefundRecord>, Error> {
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        Ok(escrow.refund_history)
-    }
-
     /// NEW: Verify escrow invariants for a specific bounty
     pub fn verify_state(env: Env, bounty_id: u64) -> bool {
         if let Some(escrow) = env
@@ -3702,9 +6212,12 @@ impl BountyEscrowContract {
     /// Number of successfully locked bounties
     ///
     /// # Errors
-    /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
+    /// * InvalidBatchSize - if batch size exceeds `get_max_batch_size` or is zero
     /// * BountyExists - if any bounty_id already exists
     /// * NotInitialized - if contract is not initialized
+    /// * ExtendedError::InsufficientAllowance - if a depositor has not approved
+    ///   the contract for the total it locks in the batch
+    /// * InsufficientBalance - if a depositor cannot cover the total it locks
     ///
     /// # Note
     /// This operation is atomic - if any item fails, the entire transaction reverts.
@@ -3724,7 +6237,7 @@ impl BountyEscrowContract {
         if batch_size == 0 {
             return Err(Error::InvalidBatchSize);
         }
-        if batch_size > MAX_BATCH_SIZE {
+        if batch_size > Self::get_max_batch_size(env.clone()) {
             return Err(Error::InvalidBatchSize);
         }
 
@@ -3752,6 +6265,7 @@ impl BountyEscrowContract {
             if item.amount <= 0 {
                 return Err(Error::InvalidAmount);
             }
+            Self::ensure_deadline_within_limits(&env, item.deadline)?;
 
             // Check for duplicate bounty_ids in the batch
             let mut count = 0u32;
@@ -3765,6 +6279,20 @@ impl BountyEscrowContract {
             }
         }
 
+        // Check each depositor can fund everything it locks up to and
+        // including this item, so a short allowance or balance fails with a
+        // typed error instead of trapping in the token contract.
+        for (i, item) in items.iter().enumerate() {
+            let mut committed: i128 = 0;
+            for other_item in items.iter().take(i + 1) {
+                if other_item.depositor == item.depositor {
+                    committed = committed.checked_add(other_item.amount).unwrap();
+                }
+            }
+            Self::ensure_depositor_allowance(&env, &item.depositor, committed);
+            Self::ensure_depositor_balance(&env, &item.depositor, committed)?;
+        }
+
         // Collect unique depositors and require auth once for each
         // This prevents "frame is already authorized" errors when same depositor appears multiple times
         let mut seen_depositors: Vec<Address> = Vec::new(&env);
@@ -3797,6 +6325,10 @@ impl BountyEscrowContract {
             env.storage()
                 .persistent()
                 .set(&DataKey::Escrow(item.bounty_id), &escrow);
+            env.storage()
+                .persistent()
+                .set(&DataKey::CreatedAt(item.bounty_id), &timestamp);
+            Self::record_status_transition(&env, item.bounty_id, EscrowStatus::Locked);
 
             // Update EscrowIndex (same as lock_funds)
             let mut index: Vec<u64> = env
@@ -3828,7 +6360,13 @@ impl BountyEscrowContract {
         let mut action_summaries: Vec<ActionSummary> = Vec::new(&env);
         let mut total_amount: i128 = 0;
         for item in items.iter() {
-            client.transfer(&item.depositor, &contract_address, &item.amount);
+            let event_seq = events::next_event_seq(&env);
+            client.transfer_from(
+                &contract_address,
+                &item.depositor,
+                &contract_address,
+                &item.amount,
+            );
             total_amount = total_amount.checked_add(item.amount).unwrap();
             action_summaries.push_back(ActionSummary {
                 bounty_id: item.bounty_id,
@@ -3840,10 +6378,13 @@ impl BountyEscrowContract {
                 &env,
                 FundsLocked {
                     version: EVENT_VERSION_V2,
+                    event_seq,
                     bounty_id: item.bounty_id,
                     amount: item.amount,
                     depositor: item.depositor.clone(),
                     deadline: item.deadline,
+                    is_topup: false,
+                    client_ref: None,
                 },
             );
         }
@@ -3884,7 +6425,7 @@ impl BountyEscrowContract {
     /// Number of successfully released bounties
     ///
     /// # Errors
-    /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
+    /// * InvalidBatchSize - if batch size exceeds `get_max_batch_size` or is zero
     /// * BountyNotFound - if any bounty_id doesn't exist
     /// * FundsNotLocked - if any bounty is not in Locked status
     /// * Unauthorized - if caller is not admin
@@ -3908,7 +6449,7 @@ impl BountyEscrowContract {
         if batch_size == 0 {
             return Err(Error::InvalidBatchSize);
         }
-        if batch_size > MAX_BATCH_SIZE {
+        if batch_size > Self::get_max_batch_size(env.clone()) {
             return Err(Error::InvalidBatchSize);
         }
 
@@ -3937,6 +6478,7 @@ impl BountyEscrowContract {
             {
                 return Err(Error::BountyNotFound);
             }
+            Self::ensure_payout_allowed(&env, &item.contributor)?;
 
             let escrow: Escrow = env
                 .storage()
@@ -3947,6 +6489,9 @@ impl BountyEscrowContract {
             if escrow.status != EscrowStatus::Locked {
                 return Err(Error::FundsNotLocked);
             }
+            Self::ensure_not_high_value(&env, item.bounty_id);
+            Self::ensure_reveal_not_required(&env)?;
+            Self::ensure_min_lifetime_elapsed(&env, item.bounty_id)?;
 
             let mut count = 0u32;
             for other_item in items.iter() {
@@ -3959,13 +6504,13 @@ impl BountyEscrowContract {
             }
 
             total_amount = total_amount
-                .checked_add(escrow.amount)
+                .checked_add(escrow.remaining_amount)
                 .ok_or(Error::InvalidAmount)?;
         }
 
         // EFFECTS: update all escrow records before any external calls (CEI)
-        // We collect (contributor, amount) pairs for the transfer pass.
-        let mut release_pairs: Vec<(Address, i128)> = Vec::new(&env);
+        // We collect (contributor, amount, deadline) tuples for the transfer pass.
+        let mut release_pairs: Vec<(Address, i128, u64)> = Vec::new(&env);
         let mut released_count = 0u32;
         for item in items.iter() {
             let mut escrow: Escrow = env
@@ -3974,9 +6519,12 @@ impl BountyEscrowContract {
                 .get(&DataKey::Escrow(item.bounty_id))
                 .unwrap();
 
-            let amount = escrow.amount;
+            // Pay out what is left after any earlier partial releases
+            let amount = escrow.remaining_amount;
             escrow.status = EscrowStatus::Released;
+            Self::record_status_transition(&env, item.bounty_id, EscrowStatus::Released);
             escrow.remaining_amount = 0;
+            Self::record_released_amount(&env, item.bounty_id, amount);
             env.storage()
                 .persistent()
                 .set(&DataKey::Escrow(item.bounty_id), &escrow);
@@ -3984,14 +6532,15 @@ impl BountyEscrowContract {
                 .persistent()
                 .set(&DataKey::CompletedAt(item.bounty_id), &timestamp);
 
-            release_pairs.push_back((item.contributor.clone(), amount));
+            release_pairs.push_back((item.contributor.clone(), amount, escrow.deadline));
             released_count += 1;
         }
 
         // INTERACTION: all external token transfers happen after state is finalized
         let mut action_summaries: Vec<ActionSummary> = Vec::new(&env);
         for (idx, item) in items.iter().enumerate() {
-            let (ref contributor, amount) = release_pairs.get(idx as u32).unwrap();
+            let (ref contributor, amount, deadline) = release_pairs.get(idx as u32).unwrap();
+            let event_seq = events::next_event_seq(&env);
             client.transfer(&contract_address, contributor, &amount);
             action_summaries.push_back(ActionSummary {
                 bounty_id: item.bounty_id,
@@ -4003,10 +6552,13 @@ impl BountyEscrowContract {
                 &env,
                 FundsReleased {
                     version: EVENT_VERSION_V2,
+                    event_seq,
                     bounty_id: item.bounty_id,
                     amount,
                     recipient: contributor.clone(),
                     timestamp,
+                    client_ref: None,
+                    deadline,
                 },
             );
         }
@@ -4036,6 +6588,13 @@ impl BountyEscrowContract {
         reentrancy_guard::release(&env);
         Ok(released_count)
     }
+    /// Set or replace the metadata of a bounty (admin only).
+    ///
+    /// Metadata is frozen once the escrow reaches a terminal state
+    /// (`Released` or `Refunded`) so the historical record cannot be rewritten;
+    /// such edits fail with `Error::EscrowTerminal`. A `bounty_type` longer
+    /// than `MAX_BOUNTY_TYPE_LEN` bytes fails with
+    /// `ExtendedError::MetadataTooLarge`.
     pub fn update_metadata(
         env: Env,
         _admin: Address,
@@ -4051,14 +6610,26 @@ impl BountyEscrowContract {
             .ok_or(Error::NotInitialized)?;
         stored_admin.require_auth();
 
+        if let Some(escrow) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+        {
+            if matches!(
+                escrow.status,
+                EscrowStatus::Released | EscrowStatus::Refunded
+            ) {
+                return Err(Error::EscrowTerminal);
+            }
+        }
+
         let metadata = EscrowMetadata {
             repo_id,
             issue_id,
             bounty_type,
         };
-        env.storage()
-            .persistent()
-            .set(&DataKey::Metadata(bounty_id), &metadata);
+        Self::validate_metadata_size(&env, &metadata);
+        Self::store_metadata(&env, bounty_id, metadata);
         Ok(())
     }
 
@@ -4078,6 +6649,26 @@ impl BountyEscrowContract {
         monitoring::get_performance_stats(&env, function_name)
     }
 
+    /// Enable or disable per-operation metric events (admin only).
+    ///
+    /// Monitoring counters keep updating while events are off, so
+    /// `get_analytics` and `get_performance_stats` stay accurate.
+    pub fn set_monitoring_events_enabled(env: Env, enabled: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        monitoring::set_events_enabled(&env, enabled);
+        Ok(())
+    }
+
+    /// Whether per-operation metric events are published (default: true).
+    pub fn get_monitoring_events_enabled(env: Env) -> bool {
+        monitoring::events_enabled(&env)
+    }
+
     pub fn get_metadata(env: Env, bounty_id: u64) -> Result<EscrowMetadata, Error> {
         env.storage()
             .persistent()
@@ -4228,6 +6819,9 @@ impl BountyEscrowContract {
     /// * `Err(Error::Unauthorized)` - Caller is not the ticket beneficiary
     /// * `Err(Error::FundsPaused)` - Release operations are paused
     /// * `Err(Error::BountyNotFound)` - Associated bounty doesn't exist
+    /// * `Err(Error::CommitmentNotFound)` - A reveal delay is set, so releases need commit-reveal
+    /// * `ExtendedError::HighValueReleaseRequired` - The escrow is above the high-value threshold
+    /// * `Err(Error::EscrowTooYoung)` - The escrow has not reached the minimum lifetime
     pub fn claim_with_ticket(env: Env, ticket_id: u64) -> Result<(), Error> {
         // Check if release is paused
         if Self::check_paused(&env, symbol_short!("release")) {
@@ -4282,31 +6876,35 @@ impl BountyEscrowContract {
         if escrow.status != EscrowStatus::Locked {
             return Err(Error::FundsNotLocked);
         }
+        Self::ensure_not_high_value(&env, ticket.bounty_id);
+        Self::ensure_reveal_not_required(&env)?;
+        Self::ensure_min_lifetime_elapsed(&env, ticket.bounty_id)?;
 
-        // Transfer funds to beneficiary
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &ticket.beneficiary,
-            &ticket.amount,
-        );
-
-        // Mark ticket as used (prevent replay)
+        // EFFECTS: mark ticket as used (prevent replay) and release the escrow
+        // before the external call (CEI)
         ticket.used = true;
         env.storage()
             .persistent()
             .set(&DataKey::ClaimTicket(ticket_id), &ticket);
 
-        // Update escrow status to Released
         escrow.status = EscrowStatus::Released;
+        Self::record_status_transition(&env, ticket.bounty_id, EscrowStatus::Released);
+        Self::record_released_amount(&env, ticket.bounty_id, escrow.remaining_amount);
         escrow.remaining_amount = 0;
         invariants::assert_escrow(&env, &escrow);
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(ticket.bounty_id), &escrow);
 
-        // Emit event
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(
+            &env.current_contract_address(),
+            &ticket.beneficiary,
+            &ticket.amount,
+        );
+
         emit_ticket_claimed(
             &env,
             TicketClaimed {
@@ -4548,6 +7146,76 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Push an escrow's refund deadline back without topping it up.
+    ///
+    /// Unlike `renew_escrow`, this may be called by either the admin or the
+    /// escrow's depositor; `caller` must authorize the call.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `caller` is neither the admin nor the depositor
+    /// * `RenewalNotAllowed` - escrow is archived or not Locked/PartiallyRefunded
+    /// * `InvalidRenewal` - `new_deadline` is not after both now and the current deadline
+    pub fn extend_refund_deadline(
+        env: Env,
+        bounty_id: u64,
+        caller: Address,
+        new_deadline: u64,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if caller != admin && caller != escrow.depositor {
+            return Err(Error::Unauthorized);
+        }
+
+        if env
+            .storage()
+            .persistent()
+            .get::<DataKey, bool>(&DataKey::Archived(bounty_id))
+            .unwrap_or(false)
+        {
+            return Err(Error::RenewalNotAllowed);
+        }
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::RenewalNotAllowed);
+        }
+
+        let now = env.ledger().timestamp();
+        if new_deadline <= now || new_deadline <= escrow.deadline {
+            return Err(Error::InvalidRenewal);
+        }
+
+        let old_deadline = escrow.deadline;
+        escrow.deadline = new_deadline;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        emit_refund_deadline_extended(
+            &env,
+            RefundDeadlineExtended {
+                bounty_id,
+                old_deadline,
+                new_deadline,
+                extended_by: caller,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
     /// Create a new escrow cycle linked to a completed or refunded predecessor.
     ///
     /// This allows recurring programs (e.g. monthly bounties) to continue
@@ -4644,6 +7312,11 @@ impl BountyEscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(new_bounty_id), &escrow);
+        env.storage().persistent().set(
+            &DataKey::CreatedAt(new_bounty_id),
+            &env.ledger().timestamp(),
+        );
+        Self::record_status_transition(&env, new_bounty_id, EscrowStatus::Locked);
 
         // Update EscrowIndex
         let mut index: Vec<u64> = env
@@ -4710,6 +7383,7 @@ impl BountyEscrowContract {
         prev_escrow.depositor.require_auth();
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
+        let event_seq = events::next_event_seq(&env);
         client.transfer(
             &prev_escrow.depositor,
             &env.current_contract_address(),
@@ -4721,10 +7395,13 @@ impl BountyEscrowContract {
             &env,
             FundsLocked {
                 version: EVENT_VERSION_V2,
+                event_seq,
                 bounty_id: new_bounty_id,
                 amount,
                 depositor: prev_escrow.depositor.clone(),
                 deadline,
+                is_topup: false,
+                client_ref: None,
             },
         );
 
@@ -4813,17 +7490,70 @@ impl traits::EscrowInterface for BountyEscrowContract {
     }
 }
 
+impl traits::StatusQuery for BountyEscrowContract {
+    /// Get escrow status code through the trait interface
+    fn get_status(env: &Env, id: u64) -> Result<u32, crate::Error> {
+        BountyEscrowContract::get_status(env.clone(), id)
+    }
+
+    /// Get remaining escrow amount through the trait interface
+    fn get_remaining(env: &Env, id: u64) -> Result<i128, crate::Error> {
+        BountyEscrowContract::get_remaining(env.clone(), id)
+    }
+}
+
+impl traits::ConfigurableFee for BountyEscrowContract {
+    /// Update fee configuration through the trait interface
+    fn update_fee_config(
+        env: &Env,
+        lock_fee_rate: Option<i128>,
+        release_fee_rate: Option<i128>,
+        fee_recipient: Option<Address>,
+        fee_enabled: Option<bool>,
+    ) -> Result<(), crate::Error> {
+        BountyEscrowContract::update_fee_config(
+            env.clone(),
+            lock_fee_rate,
+            release_fee_rate,
+            fee_recipient,
+            fee_enabled,
+        )
+    }
+
+    /// Get fee configuration through the trait interface
+    fn get_fee_config(env: &Env) -> crate::FeeConfig {
+        BountyEscrowContract::get_fee_config(env.clone())
+    }
+}
+
+impl traits::Pausable for BountyEscrowContract {
+    /// Set pause flags through the trait interface
+    fn set_paused(
+        env: &Env,
+        lock: Option<bool>,
+        release: Option<bool>,
+        refund: Option<bool>,
+        reason: Option<soroban_sdk::String>,
+    ) -> Result<(), crate::Error> {
+        BountyEscrowContract::set_paused(env.clone(), lock, release, refund, reason)
+    }
+
+    /// Get pause flags through the trait interface
+    fn get_pause_flags(env: &Env) -> crate::PauseFlags {
+        BountyEscrowContract::get_pause_flags(env)
+    }
+}
+
 impl traits::UpgradeInterface for BountyEscrowContract {
     /// Get contract version
-    fn get_version(_env: &Env) -> u32 {
-        1 // Current version
+    fn get_version(env: &Env) -> u32 {
+        BountyEscrowContract::get_version(env.clone())
     }
 
     /// Set contract version (admin only)
-    fn set_version(_env: &Env, _new_version: u32) -> Result<(), soroban_sdk::String> {
-        // Version management - reserved for future use
-        // Currently, version is hardcoded to 1
-        Ok(())
+    fn set_version(env: &Env, new_version: u32) -> Result<(), soroban_sdk::String> {
+        BountyEscrowContract::migrate(env.clone(), new_version)
+            .map_err(|_| soroban_sdk::String::from_str(env, "Invalid version"))
     }
 }
 
@@ -4857,6 +7587,8 @@ mod test_front_running_ordering;
 #[cfg(test)]
 mod test_granular_pause;
 #[cfg(test)]
+mod test_high_value_release;
+#[cfg(test)]
 mod test_invariants;
 mod test_lifecycle;
 #[cfg(test)]
@@ -4868,8 +7600,12 @@ mod test_partial_payout_rounding;
 #[cfg(test)]
 mod test_pause;
 #[cfg(test)]
+mod test_payout_allowlist;
+#[cfg(test)]
 mod test_reentrancy_guard;
 #[cfg(test)]
+mod test_solvency;
+#[cfg(test)]
 mod escrow_status_transition_tests {
     use super::*;
     use soroban_sdk::{
@@ -29,6 +29,15 @@
 //!   so early error returns after `acquire` are safe.
 //! - The same guard key is shared across all protected functions, providing
 //!   cross-function reentrancy protection.
+//!
+//! ## Token Callbacks
+//!
+//! A token whose `transfer` calls back into the escrow (ERC-777-style hooks)
+//! is rejected by the Soroban host itself: a contract already on the call
+//! stack cannot be re-entered, and this applies to view functions as well.
+//! The guard is defence in depth on top of that. Every release and refund
+//! path also commits escrow state before calling `transfer`, so a callback
+//! that could observe the escrow would see the post-payout state.
 
 use super::DataKey;
 use soroban_sdk::Env;
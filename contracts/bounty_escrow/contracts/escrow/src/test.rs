@@ -145,6 +145,12 @@ impl<'a> TestSetup<'a> {
 
         // Mint tokens to depositor
         token_admin.mint(&depositor, &1_000_000);
+        token.approve(
+            &depositor,
+            &escrow.address,
+            &i128::MAX,
+            &(env.ledger().sequence() + 10_000),
+        );
 
         Self {
             env,
@@ -1100,6 +1106,89 @@ fn test_batch_lock_funds_at_max_batch_size() {
     assert_eq!(count, 20);
 }
 
+#[test]
+fn test_max_batch_size_defaults_to_twenty() {
+    let setup = TestSetup::new();
+    assert_eq!(setup.escrow.get_max_batch_size(), 20);
+}
+
+#[test]
+fn test_configured_max_batch_size_rejects_larger_batches() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup.escrow.set_max_batch_size(&2);
+    assert_eq!(setup.escrow.get_max_batch_size(), 2);
+
+    let mut items = Vec::new(&setup.env);
+    for i in 1..=3 {
+        items.push_back(LockFundsItem {
+            bounty_id: i,
+            depositor: setup.depositor.clone(),
+            amount: 100,
+            deadline,
+        });
+    }
+    assert_eq!(
+        setup.escrow.try_batch_lock_funds(&items),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+
+    items.pop_back();
+    assert_eq!(setup.escrow.batch_lock_funds(&items), 2);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &100, &deadline);
+
+    let mut releases = Vec::new(&setup.env);
+    for i in 1..=3 {
+        releases.push_back(ReleaseFundsItem {
+            bounty_id: i,
+            contributor: setup.contributor.clone(),
+        });
+    }
+    assert_eq!(
+        setup.escrow.try_batch_release_funds(&releases),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
+
+#[test]
+fn test_set_max_batch_size_rejects_out_of_range() {
+    let setup = TestSetup::new();
+
+    assert_eq!(
+        setup.escrow.try_set_max_batch_size(&0),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+    assert_eq!(
+        setup.escrow.try_set_max_batch_size(&501),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+    setup.escrow.set_max_batch_size(&500);
+    assert_eq!(setup.escrow.get_max_batch_size(), 500);
+}
+
+#[test]
+#[should_panic(expected = "InvalidAction")]
+fn test_set_max_batch_size_requires_admin() {
+    let setup = TestSetup::new();
+    let non_admin = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .mock_auths(&[soroban_sdk::testutils::MockAuth {
+            address: &non_admin,
+            invoke: &soroban_sdk::testutils::MockAuthInvoke {
+                contract: &setup.escrow.address,
+                fn_name: "set_max_batch_size",
+                args: soroban_sdk::IntoVal::into_val(&(2u32,), &setup.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_max_batch_size(&2);
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #3)")]
 fn test_batch_lock_funds_duplicate_bounty_id() {
@@ -1667,6 +1756,12 @@ fn test_batch_operations_multiple_depositors() {
     let initial_depositor_balance = setup.token.balance(&setup.depositor);
 
     setup.token_admin.mint(&depositor2, &5000);
+    setup.token.approve(
+        &depositor2,
+        &setup.escrow.address,
+        &i128::MAX,
+        &(setup.env.ledger().sequence() + 10_000),
+    );
 
     let items = vec![
         &setup.env,
@@ -8,12 +8,14 @@
 ///
 /// ## Coverage
 /// * `get_aggregate_stats`  – totals update after lock → release → refund lifecycle
+/// * `get_aggregate_stats_bounded` – caps the scan and flags partial results
 /// * `get_escrow_count`     – increments on each lock; never decrements
 /// * `query_escrows_by_status` – returns correct subset filtered by status
 /// * `query_escrows_by_amount` – range filter works for locked, released, and mixed states
 /// * `query_escrows_by_deadline` – deadline range filter returns correct bounties
 /// * `query_escrows_by_depositor` – per-depositor index is populated on lock
 /// * `get_escrow_ids_by_status` – ID-only view mirrors full-object equivalent
+/// * `get_escrows_by_status` – status buckets follow every transition
 /// * `get_refund_eligibility` – eligibility flags flip correctly across lifecycle
 /// * `get_refund_history`    – history vector is populated by approved-refund path
 /// * Monitoring event emission – lock/release/refund each emit ≥ 1 event
@@ -22,7 +24,7 @@ use crate::{BountyEscrowContract, BountyEscrowContractClient, EscrowStatus, Refu
 use soroban_sdk::{
     symbol_short,
     testutils::{Address as _, Events, Ledger},
-    token, Address, Env,
+    token, Address, Env, IntoVal,
 };
 
 // ---------------------------------------------------------------------------
@@ -46,6 +48,16 @@ fn create_escrow_contract<'a>(e: &'a Env) -> BountyEscrowContractClient<'a> {
     BountyEscrowContractClient::new(e, &contract_id)
 }
 
+/// Approves `escrow` to pull any amount of `from`'s tokens, which `lock_funds` needs.
+fn approve_escrow(token: &token::Client, from: &Address, escrow: &Address) {
+    token.approve(
+        from,
+        escrow,
+        &i128::MAX,
+        &(token.env.ledger().sequence() + 10_000),
+    );
+}
+
 // ===========================================================================
 // 1. Aggregate stats – lock path
 // ===========================================================================
@@ -79,6 +91,7 @@ fn test_aggregate_stats_reflects_single_lock() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &1, &500, &deadline);
@@ -101,6 +114,7 @@ fn test_aggregate_stats_reflects_multiple_locks() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &10_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &10, &1_000, &deadline);
@@ -129,6 +143,7 @@ fn test_aggregate_stats_after_release_moves_to_released_bucket() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &20, &1_000, &deadline);
@@ -154,6 +169,7 @@ fn test_aggregate_stats_mixed_lock_and_release() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     // Lock three, release one, keep two locked
@@ -170,6 +186,71 @@ fn test_aggregate_stats_mixed_lock_and_release() {
     assert_eq!(stats.total_released, 700);
 }
 
+#[test]
+fn test_aggregate_stats_counts_partial_releases() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow.lock_funds(&depositor, &33, &1_000, &deadline);
+    escrow.partial_release(&33, &contributor, &400);
+    escrow.partial_release(&33, &contributor, &400);
+
+    assert_eq!(escrow.get_escrow_released_amount(&33), 800);
+    let stats = escrow.get_aggregate_stats();
+    assert_eq!(stats.count_locked, 1);
+    assert_eq!(stats.total_released, 800);
+
+    escrow.release_funds(&33, &contributor);
+    assert_eq!(escrow.get_escrow_released_amount(&33), 1_000);
+    let stats = escrow.get_aggregate_stats();
+    assert_eq!(stats.count_released, 1);
+    assert_eq!(stats.total_released, 1_000);
+}
+
+#[test]
+fn test_aggregate_stats_partial_release_then_refund_matches_deposit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow.lock_funds(&depositor, &34, &1_000, &deadline);
+    escrow.partial_release(&34, &contributor, &300);
+
+    let stats = escrow.get_aggregate_stats();
+    assert_eq!(stats.total_locked, 700);
+    assert_eq!(stats.total_released, 300);
+    assert_eq!(stats.total_refunded, 0);
+
+    env.ledger().set_timestamp(deadline + 1);
+    escrow.refund(&34);
+
+    let stats = escrow.get_aggregate_stats();
+    assert_eq!(stats.total_locked, 0);
+    assert_eq!(stats.total_released, 300);
+    assert_eq!(stats.total_refunded, 700);
+    assert_eq!(
+        stats.total_locked + stats.total_released + stats.total_refunded,
+        1_000
+    );
+}
+
 // ===========================================================================
 // 3. Aggregate stats – refund path
 // ===========================================================================
@@ -184,6 +265,7 @@ fn test_aggregate_stats_after_refund_moves_to_refunded_bucket() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 500;
     escrow.lock_funds(&depositor, &40, &900, &deadline);
@@ -210,6 +292,7 @@ fn test_aggregate_stats_full_lifecycle_lock_release_refund() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &10_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let now = env.ledger().timestamp();
     // One of each outcome
@@ -232,6 +315,64 @@ fn test_aggregate_stats_full_lifecycle_lock_release_refund() {
     assert_eq!(stats.total_refunded, 2_000);
 }
 
+#[test]
+fn test_aggregate_stats_bounded_reports_truncation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &10_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    for id in 60u64..65 {
+        escrow.lock_funds(&depositor, &id, &1_000, &deadline);
+    }
+
+    let (partial, truncated) = escrow.get_aggregate_stats_bounded(&3);
+    assert!(truncated);
+    assert_eq!(partial.count_locked, 3);
+    assert_eq!(partial.total_locked, 3_000);
+
+    let (full, truncated) = escrow.get_aggregate_stats_bounded(&5);
+    assert!(!truncated);
+    assert_eq!(full, escrow.get_aggregate_stats());
+    assert_eq!(full.count_locked, 5);
+}
+
+#[test]
+fn test_active_escrow_count_tracks_terminal_transitions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &10_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
+
+    let now = env.ledger().timestamp();
+    escrow.lock_funds(&depositor, &53, &1_000, &(now + 500));
+    escrow.lock_funds(&depositor, &54, &2_000, &(now + 500));
+    escrow.lock_funds(&depositor, &55, &3_000, &(now + 5000));
+    assert_eq!(escrow.get_active_escrow_count(), 3);
+
+    escrow.release_funds(&53, &contributor);
+    env.ledger().set_timestamp(now + 501);
+    escrow.refund(&54);
+    assert_eq!(escrow.get_active_escrow_count(), 1);
+
+    // Repeated terminal calls fail and must not decrement again
+    assert!(escrow.try_release_funds(&53, &contributor).is_err());
+    assert!(escrow.try_refund(&54).is_err());
+    assert_eq!(escrow.get_active_escrow_count(), 1);
+}
+
 // ===========================================================================
 // 4. Escrow count monitoring view
 // ===========================================================================
@@ -258,6 +399,7 @@ fn test_escrow_count_increments_on_each_lock() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
 
@@ -284,6 +426,7 @@ fn test_escrow_count_does_not_decrement_after_release() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &63, &500, &deadline);
@@ -303,6 +446,7 @@ fn test_escrow_count_does_not_decrement_after_refund() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 500;
     escrow.lock_funds(&depositor, &64, &500, &deadline);
@@ -327,6 +471,7 @@ fn test_query_by_status_locked_returns_only_locked() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &70, &100, &deadline);
@@ -360,6 +505,7 @@ fn test_query_by_status_released_returns_only_released() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &80, &400, &deadline);
@@ -385,6 +531,7 @@ fn test_query_by_status_refunded_returns_only_refunded() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let now = env.ledger().timestamp();
     escrow.lock_funds(&depositor, &90, &600, &(now + 500));
@@ -407,6 +554,7 @@ fn test_query_by_status_empty_when_no_match() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &95, &100, &deadline);
@@ -426,6 +574,7 @@ fn test_query_by_status_pagination_offset_and_limit() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 2000;
     // Lock 5 bounties, all remain locked
@@ -459,6 +608,7 @@ fn test_query_by_amount_range_returns_matching_escrows() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &10_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 2000;
     escrow.lock_funds(&depositor, &110, &100, &deadline);
@@ -485,6 +635,7 @@ fn test_query_by_amount_exact_boundaries_included() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &10_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 2000;
     escrow.lock_funds(&depositor, &120, &1_000, &deadline);
@@ -505,6 +656,7 @@ fn test_query_by_amount_no_results_outside_range() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 2000;
     escrow.lock_funds(&depositor, &130, &50, &deadline);
@@ -528,6 +680,7 @@ fn test_query_by_deadline_range_filters_correctly() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let now = env.ledger().timestamp();
     escrow.lock_funds(&depositor, &140, &100, &(now + 100));
@@ -554,6 +707,7 @@ fn test_query_by_deadline_exact_boundary_included() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let now = env.ledger().timestamp();
     escrow.lock_funds(&depositor, &150, &100, &(now + 1_000));
@@ -581,7 +735,9 @@ fn test_query_by_depositor_returns_only_that_depositors_escrows() {
     escrow.init(&admin, &token.address);
 
     token_admin.mint(&depositor_a, &5_000);
+    approve_escrow(&token, &depositor_a, &escrow.address);
     token_admin.mint(&depositor_b, &5_000);
+    approve_escrow(&token, &depositor_b, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor_a, &160, &1_000, &deadline);
@@ -609,6 +765,7 @@ fn test_query_by_depositor_returns_empty_for_unknown_address() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &165, &100, &deadline);
@@ -633,6 +790,7 @@ fn test_get_escrow_ids_by_status_returns_correct_ids() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &170, &100, &deadline);
@@ -660,6 +818,7 @@ fn test_get_escrow_ids_by_status_empty_when_no_match() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &175, &100, &deadline);
@@ -668,6 +827,117 @@ fn test_get_escrow_ids_by_status_empty_when_no_match() {
     assert_eq!(released_ids.len(), 0);
 }
 
+#[test]
+fn test_get_escrows_by_status_moves_bounty_between_buckets() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &10_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
+
+    let locked = EscrowStatus::Locked.code();
+    let released = EscrowStatus::Released.code();
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow.lock_funds(&depositor, &71, &1_000, &deadline);
+    escrow.lock_funds(&depositor, &72, &2_000, &deadline);
+    escrow.lock_funds(&depositor, &73, &3_000, &deadline);
+
+    let bucket = escrow.get_escrows_by_status(&locked, &0, &10);
+    assert_eq!(bucket.len(), 3);
+    assert_eq!(bucket.get(0).unwrap().bounty_id, 71);
+    assert_eq!(escrow.get_escrows_by_status(&released, &0, &10).len(), 0);
+
+    escrow.release_funds(&72, &contributor);
+
+    let bucket = escrow.get_escrows_by_status(&locked, &0, &10);
+    assert_eq!(bucket.len(), 2);
+    assert_eq!(bucket.get(0).unwrap().bounty_id, 71);
+    assert_eq!(bucket.get(1).unwrap().bounty_id, 73);
+    let bucket = escrow.get_escrows_by_status(&released, &0, &10);
+    assert_eq!(bucket.len(), 1);
+    assert_eq!(bucket.get(0).unwrap().bounty_id, 72);
+    assert_eq!(bucket.get(0).unwrap().escrow.status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_get_escrows_by_status_paginates_and_ignores_unknown_codes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &10_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    for id in 80u64..85 {
+        escrow.lock_funds(&depositor, &id, &1_000, &deadline);
+    }
+
+    let locked = EscrowStatus::Locked.code();
+    let page = escrow.get_escrows_by_status(&locked, &2, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().bounty_id, 82);
+    assert_eq!(page.get(1).unwrap().bounty_id, 83);
+    assert_eq!(escrow.get_escrows_by_status(&locked, &4, &10).len(), 1);
+    assert_eq!(escrow.get_escrows_by_status(&locked, &9, &10).len(), 0);
+    assert_eq!(escrow.get_escrows_by_status(&99, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_get_escrows_by_status_spans_index_pages() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+    let admin = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    for id in 1u64..=120 {
+        // Separate depositors keep the anti-abuse limits out of the way.
+        let depositor = Address::generate(&env);
+        token_admin.mint(&depositor, &1_000);
+        approve_escrow(&token, &depositor, &escrow.address);
+        escrow.lock_funds(&depositor, &id, &1_000, &deadline);
+    }
+
+    // Releasing from the first page pulls entries back from the last one.
+    for id in [1u64, 2, 60, 120] {
+        escrow.release_funds(&id, &contributor);
+    }
+
+    let locked = EscrowStatus::Locked.code();
+    let mut seen = soroban_sdk::Vec::<u64>::new(&env);
+    let mut offset = 0;
+    loop {
+        let page = escrow.get_escrows_by_status(&locked, &offset, &30);
+        if page.is_empty() {
+            break;
+        }
+        for entry in page.iter() {
+            assert_eq!(entry.escrow.status, EscrowStatus::Locked);
+            assert!(!seen.contains(entry.bounty_id));
+            seen.push_back(entry.bounty_id);
+        }
+        offset += page.len();
+    }
+    assert_eq!(seen.len(), 116);
+    assert!(!seen.contains(60));
+
+    let released = escrow.get_escrows_by_status(&EscrowStatus::Released.code(), &0, &10);
+    assert_eq!(released.len(), 4);
+}
+
 // ===========================================================================
 // 10. Refund eligibility analytics view
 // ===========================================================================
@@ -682,6 +952,7 @@ fn test_refund_eligibility_false_before_deadline() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 2000;
     escrow.lock_funds(&depositor, &180, &1_000, &deadline);
@@ -704,6 +975,7 @@ fn test_refund_eligibility_true_after_deadline_passes() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 500;
     escrow.lock_funds(&depositor, &181, &1_000, &deadline);
@@ -728,6 +1000,7 @@ fn test_refund_eligibility_false_after_release() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 2000;
     escrow.lock_funds(&depositor, &182, &1_000, &deadline);
@@ -749,6 +1022,7 @@ fn test_refund_eligibility_true_with_admin_approval_before_deadline() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 5000;
     escrow.lock_funds(&depositor, &183, &1_000, &deadline);
@@ -779,6 +1053,7 @@ fn test_refund_history_empty_before_any_refund() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 2000;
     escrow.lock_funds(&depositor, &190, &1_000, &deadline);
@@ -818,6 +1093,7 @@ fn test_lock_emits_at_least_one_event() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let before = env.events().all().len();
     let deadline = env.ledger().timestamp() + 1000;
@@ -841,6 +1117,7 @@ fn test_release_emits_at_least_one_event() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &201, &1_000, &deadline);
@@ -865,6 +1142,7 @@ fn test_refund_emits_at_least_one_event() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 500;
     escrow.lock_funds(&depositor, &202, &1_000, &deadline);
@@ -890,6 +1168,7 @@ fn test_event_count_scales_linearly_with_locks() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
 
@@ -927,6 +1206,7 @@ fn test_duplicate_lock_does_not_affect_first_lock_state() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &220, &1_000, &deadline);
@@ -948,6 +1228,7 @@ fn test_analytics_invariant_total_amounts_are_non_negative() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let now = env.ledger().timestamp();
     escrow.lock_funds(&depositor, &240, &500, &(now + 500));
@@ -983,6 +1264,7 @@ fn test_count_matches_query_by_status_total() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &250, &100, &deadline);
@@ -1013,6 +1295,7 @@ fn test_ids_view_matches_full_object_view_count() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &260, &100, &deadline);
@@ -1040,6 +1323,7 @@ fn test_aggregate_stats_consistent_with_individual_escrow_queries() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &10_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let now = env.ledger().timestamp();
     escrow.lock_funds(&depositor, &270, &1_000, &(now + 1000));
@@ -1083,6 +1367,7 @@ fn test_get_balance_matches_locked_total_after_locks() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &10_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &280, &1_000, &deadline);
@@ -1111,6 +1396,7 @@ fn test_get_balance_decreases_after_release() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &10_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow.lock_funds(&depositor, &290, &1_000, &deadline);
@@ -1135,6 +1421,7 @@ fn test_get_balance_zero_after_all_escrows_settled() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &10_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let now = env.ledger().timestamp();
     escrow.lock_funds(&depositor, &295, &1_000, &(now + 500));
@@ -1180,6 +1467,7 @@ fn test_monitoring_analytics_tracks_successful_operations() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let now = env.ledger().timestamp();
 
@@ -1206,6 +1494,7 @@ fn test_monitoring_analytics_tracks_failed_operations() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let now = env.ledger().timestamp();
 
@@ -1255,6 +1544,7 @@ fn test_monitoring_state_snapshot_captures_current_metrics() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let now = env.ledger().timestamp();
     escrow.lock_funds(&depositor, &700, &1000, &(now + 1000));
@@ -1275,6 +1565,7 @@ fn test_comprehensive_analytics_flow() {
     let escrow = create_escrow_contract(&env);
     escrow.init(&admin, &token.address);
     token_admin.mint(&depositor, &1_000_000);
+    approve_escrow(&token, &depositor, &escrow.address);
 
     let now = env.ledger().timestamp();
 
@@ -1326,3 +1617,93 @@ fn test_error_rate_calculation_various_inputs() {
     // 2/10 * 10000 = 2000 basis points
     assert_eq!(analytics.error_rate, 2000);
 }
+
+// ===========================================================================
+// 17. Metric event suppression
+// ===========================================================================
+
+fn metric_event_count(env: &Env, contract: &Address) -> u32 {
+    let metric = symbol_short!("metric").into_val(env);
+    let mut count = 0;
+    for (emitter, topics, _) in env.events().all().iter() {
+        if &emitter == contract && topics.get(0).unwrap().shallow_eq(&metric) {
+            count += 1;
+        }
+    }
+    count
+}
+
+#[test]
+fn test_metric_events_enabled_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token, &depositor, &escrow.address);
+
+    assert!(escrow.get_monitoring_events_enabled());
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    escrow.lock_funds(&depositor, &1, &1_000, &deadline);
+    assert_eq!(metric_event_count(&env, &escrow.address), 1);
+}
+
+#[test]
+fn test_disabled_metric_events_still_increment_counters() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token, &depositor, &escrow.address);
+
+    escrow.set_monitoring_events_enabled(&false);
+    assert!(!escrow.get_monitoring_events_enabled());
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    escrow.lock_funds(&depositor, &1, &1_000, &deadline);
+    assert_eq!(metric_event_count(&env, &escrow.address), 0);
+    escrow.release_funds(&1, &contributor);
+    assert_eq!(metric_event_count(&env, &escrow.address), 0);
+
+    env.as_contract(&escrow.address, || {
+        crate::monitoring::emit_performance(&env, symbol_short!("lock"), 5);
+    });
+    assert_eq!(metric_event_count(&env, &escrow.address), 0);
+
+    assert_eq!(escrow.get_analytics().operation_count, 2);
+    assert_eq!(
+        escrow
+            .get_performance_stats(&symbol_short!("lock"))
+            .call_count,
+        1
+    );
+}
+
+#[test]
+fn test_metric_events_can_be_reenabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token, &depositor, &escrow.address);
+
+    escrow.set_monitoring_events_enabled(&false);
+    escrow.set_monitoring_events_enabled(&true);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    escrow.lock_funds(&depositor, &1, &1_000, &deadline);
+    assert_eq!(metric_event_count(&env, &escrow.address), 1);
+}
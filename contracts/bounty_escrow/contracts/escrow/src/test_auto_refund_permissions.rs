@@ -23,7 +23,7 @@ fn create_escrow_contract<'a>(e: &Env) -> BountyEscrowContractClient<'a> {
 
 struct TestSetup<'a> {
     env: Env,
-    _admin: Address, // Added underscore
+    admin: Address,
     depositor: Address,
     _random_user: Address, // Added underscore
     token: token::Client<'a>,
@@ -45,10 +45,16 @@ impl<'a> TestSetup<'a> {
 
         escrow.init(&admin, &token.address);
         token_admin.mint(&depositor, &1_000_000);
+        token.approve(
+            &depositor,
+            &escrow.address,
+            &i128::MAX,
+            &(env.ledger().sequence() + 10_000),
+        );
 
         Self {
             env,
-            _admin: admin,
+            admin,
             depositor,
             _random_user: random_user,
             token,
@@ -278,3 +284,327 @@ fn test_auto_refund_different_users_same_result() {
         initial_balance + (amount * 2)
     );
 }
+
+#[test]
+fn test_refund_grace_period_defaults_to_zero() {
+    let setup = TestSetup::new();
+    assert_eq!(setup.escrow.get_refund_grace_period(), 0);
+
+    setup.escrow.set_refund_grace_period(&600);
+    assert_eq!(setup.escrow.get_refund_grace_period(), 600);
+}
+
+#[test]
+fn test_refund_grace_period_rejects_refund_before_deadline() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.set_refund_grace_period(&600);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    setup.env.ledger().set_timestamp(deadline - 1);
+    assert_eq!(
+        setup.escrow.try_refund(&1),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+}
+
+#[test]
+fn test_refund_grace_period_is_admin_only_within_window() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.set_refund_grace_period(&600);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    setup.env.ledger().set_timestamp(deadline + 599);
+    // Nobody signs: rejected while in the grace window
+    assert!(setup.escrow.mock_auths(&[]).try_refund(&1).is_err());
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+
+    setup.env.mock_all_auths();
+    setup.escrow.refund(&1);
+    assert_eq!(
+        setup.env.auths()[0].0,
+        setup.admin,
+        "refund inside the grace window must be authorized by the admin"
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_refund_grace_period_permissionless_after_window() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.set_refund_grace_period(&600);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    setup.env.ledger().set_timestamp(deadline + 600);
+    setup.escrow.mock_auths(&[]).refund(&1);
+
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+}
+
+#[test]
+fn test_approved_partial_refund_to_depositor_before_deadline() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let amount = 1000;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    let depositor_before = setup.token.balance(&setup.depositor);
+
+    setup
+        .escrow
+        .approve_refund(&bounty_id, &300, &setup.depositor, &RefundMode::Partial);
+    setup.escrow.refund(&bounty_id);
+
+    let escrow = setup.escrow.get_escrow_info(&bounty_id);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyRefunded);
+    assert_eq!(escrow.remaining_amount, 700);
+    assert_eq!(
+        setup.token.balance(&setup.depositor),
+        depositor_before + 300
+    );
+
+    let history = setup.escrow.get_refund_history(&bounty_id);
+    assert_eq!(history.len(), 1);
+    let record = history.get(0).unwrap();
+    assert_eq!(record.recipient, setup.depositor);
+    assert_eq!(record.mode, RefundMode::Partial);
+
+    // The approval is consumed: a second early refund is rejected again.
+    assert_eq!(
+        setup.escrow.try_refund(&bounty_id),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+}
+
+#[test]
+fn test_unapproved_partial_refund_before_deadline_rejected() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let amount = 1000;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+
+    assert_eq!(
+        setup.escrow.try_refund(&bounty_id),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+    let escrow = setup.escrow.get_escrow_info(&bounty_id);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.remaining_amount, amount);
+}
+
+#[test]
+fn test_early_refund_request_approve_refund_flow() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let amount = 1000;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    let balance_before = setup.token.balance(&setup.depositor);
+
+    setup.escrow.request_early_refund(&bounty_id);
+    assert!(setup.escrow.has_early_refund_request(&bounty_id));
+
+    setup.escrow.approve_early_refund(&bounty_id);
+    assert!(!setup.escrow.has_early_refund_request(&bounty_id));
+
+    setup.escrow.refund(&bounty_id);
+
+    let escrow = setup.escrow.get_escrow_info(&bounty_id);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(
+        setup.token.balance(&setup.depositor),
+        balance_before + amount
+    );
+}
+
+#[test]
+fn test_early_refund_without_approval_rejected_before_deadline() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let amount = 1000;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+
+    // Approving without a depositor request is rejected
+    assert_eq!(
+        setup.escrow.try_approve_early_refund(&bounty_id),
+        Err(Ok(Error::RefundNotApproved))
+    );
+
+    // A pending request alone does not allow a refund
+    setup.escrow.request_early_refund(&bounty_id);
+    assert_eq!(
+        setup.escrow.try_refund(&bounty_id),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&bounty_id).status,
+        EscrowStatus::Locked
+    );
+}
+
+#[test]
+fn test_get_expirable_bounties_returns_only_past_deadline() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &(now + 100));
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &100, &(now + 5_000));
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &100, &(now + 200));
+    setup.env.ledger().set_timestamp(now + 200);
+
+    let expirable = setup.escrow.get_expirable_bounties(&0, &10);
+    assert_eq!(expirable, soroban_sdk::vec![&setup.env, 1u64, 3u64]);
+
+    // Paging is over the escrow index, not over matches
+    let second_page = setup.escrow.get_expirable_bounties(&1, &1);
+    assert_eq!(second_page.len(), 0);
+
+    // Refunded escrows drop out
+    setup.escrow.refund(&1);
+    assert_eq!(
+        setup.escrow.get_expirable_bounties(&0, &10),
+        soroban_sdk::vec![&setup.env, 3u64]
+    );
+}
+
+fn partial_refund_n_times(setup: &TestSetup, bounty_id: u64, n: u32) {
+    for i in 0..n {
+        // Distinct amounts make each record identifiable.
+        let amount = 10 + i as i128;
+        setup
+            .escrow
+            .approve_refund(&bounty_id, &amount, &setup.depositor, &RefundMode::Partial);
+        setup.escrow.refund(&bounty_id);
+    }
+}
+
+#[test]
+fn test_refund_history_archives_beyond_inline_cap() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &1_000, &deadline);
+    setup.escrow.set_refund_history_cap(&3);
+
+    partial_refund_n_times(&setup, bounty_id, 7);
+
+    // Only the newest three records stay inline in the escrow.
+    let escrow = setup.escrow.get_escrow_info(&bounty_id);
+    assert_eq!(escrow.refund_history.len(), 3);
+    assert_eq!(escrow.refund_history.get(0).unwrap().amount, 14);
+    assert_eq!(
+        escrow.remaining_amount,
+        1_000 - (10 + 11 + 12 + 13 + 14 + 15 + 16)
+    );
+
+    // The full history, archived records included, is still retrievable in order.
+    let history = setup.escrow.get_refund_history(&bounty_id);
+    assert_eq!(history.len(), 7);
+    for (i, record) in history.iter().enumerate() {
+        assert_eq!(record.amount, 10 + i as i128);
+    }
+}
+
+#[test]
+fn test_refund_history_page_spans_archive_and_inline() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &1_000, &deadline);
+    setup.escrow.set_refund_history_cap(&2);
+
+    partial_refund_n_times(&setup, bounty_id, 5);
+
+    let page = setup.escrow.get_refund_history_page(&bounty_id, &2, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().amount, 12);
+    assert_eq!(page.get(1).unwrap().amount, 13);
+
+    let tail = setup.escrow.get_refund_history_page(&bounty_id, &4, &10);
+    assert_eq!(tail.len(), 1);
+    assert_eq!(tail.get(0).unwrap().amount, 14);
+
+    assert_eq!(
+        setup
+            .escrow
+            .get_refund_history_page(&bounty_id, &10, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_refund_history_count_and_inline_page() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &1_000, &deadline);
+
+    partial_refund_n_times(&setup, bounty_id, 5);
+
+    assert_eq!(setup.escrow.get_refund_history_count(&bounty_id), 5);
+    let page = setup.escrow.get_refund_history_page(&bounty_id, &2, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().amount, 12);
+    assert_eq!(page.get(1).unwrap().amount, 13);
+
+    assert_eq!(
+        setup.escrow.try_get_refund_history_count(&99),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_refund_history_cap_rejects_zero() {
+    let setup = TestSetup::new();
+    assert_eq!(setup.escrow.get_refund_history_cap(), 20);
+    assert_eq!(
+        setup.escrow.try_set_refund_history_cap(&0),
+        Err(Ok(Error::InvalidAmount))
+    );
+}
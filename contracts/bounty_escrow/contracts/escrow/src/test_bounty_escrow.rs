@@ -1,8 +1,12 @@
-use crate::{BountyEscrowContract, BountyEscrowContractClient, Error as ContractError};
+use crate::events::{FundsLocked, FundsRefunded, FundsReleased};
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, Error as ContractError, EscrowStatus,
+    ExtendedError, LockFundsItem, RefundMode, ReleaseFundsItem,
+};
 use soroban_sdk::testutils::Events;
 use soroban_sdk::{
     testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
-    token, Address, Env, IntoVal, Map, Symbol, TryFromVal, Val,
+    token, Address, Env, IntoVal, Map, String, Symbol, TryFromVal, Val,
 };
 
 fn create_test_env() -> (Env, BountyEscrowContractClient<'static>, Address) {
@@ -47,6 +51,16 @@ fn assert_current_call_has_versioned_contract_event(env: &Env, contract_id: &Add
     assert!(found);
 }
 
+/// Approves `escrow` to pull any amount of `from`'s tokens, which `lock_funds` needs.
+fn approve_escrow(token: &token::Client, from: &Address, escrow: &Address) {
+    token.approve(
+        from,
+        escrow,
+        &i128::MAX,
+        &(token.env.ledger().sequence() + 10_000),
+    );
+}
+
 #[test]
 fn test_init_event() {
     let (env, client, _contract_id) = create_test_env();
@@ -78,12 +92,13 @@ fn test_events_emit_v2_version_tags_for_all_bounty_emitters() {
     let depositor = Address::generate(&env);
     let contributor = Address::generate(&env);
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
     client.init(&admin, &token);
     assert_current_call_has_versioned_contract_event(&env, &contract_id);
 
     token_admin_client.mint(&depositor, &10_000);
+    approve_escrow(&token_client, &depositor, &client.address);
     client.lock_funds(&depositor, &1, &10_000, &(env.ledger().timestamp() + 10));
     assert_current_call_has_versioned_contract_event(&env, &contract_id);
 
@@ -106,12 +121,13 @@ fn test_lock_fund() {
 
     // Setup token
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
     // Initialize
     client.init(&admin.clone(), &token.clone());
 
     token_admin_client.mint(&depositor, &amount);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
 
@@ -138,12 +154,13 @@ fn test_release_fund() {
 
     // Setup token
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
     // Initialize
     client.init(&admin.clone(), &token.clone());
 
     token_admin_client.mint(&depositor, &amount);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
 
@@ -180,9 +197,10 @@ fn test_lock_funds_zero_amount_edge_case() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
 
@@ -192,7 +210,6 @@ fn test_lock_funds_zero_amount_edge_case() {
 }
 
 #[test]
-#[should_panic] // Token transfer fails due to insufficient balance, protecting against overflows/invalid accounting.
 fn test_lock_funds_insufficient_balance_rejected() {
     let (env, client, _contract_id) = create_test_env();
     let admin = Address::generate(&env);
@@ -203,11 +220,117 @@ fn test_lock_funds_insufficient_balance_rejected() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &100);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let res = client.try_lock_funds(&depositor, &bounty_id, &1_000, &deadline);
+    assert_eq!(res, Err(Ok(ContractError::InsufficientBalance)));
+    assert!(client.try_get_escrow_info(&bounty_id).is_err());
+}
+
+#[test]
+fn test_lock_funds_insufficient_allowance_rejected() {
+    let (env, client, _contract_id) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let bounty_id = 102;
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    token_admin_client.mint(&depositor, &10_000);
+
+    let res = client.try_lock_funds(&depositor, &bounty_id, &1_000, &deadline);
+    assert_eq!(res, Err(Err(ExtendedError::InsufficientAllowance.into())));
 
+    token_client.approve(
+        &depositor,
+        &client.address,
+        &999,
+        &(env.ledger().sequence() + 10_000),
+    );
+    let res = client.try_lock_funds(&depositor, &bounty_id, &1_000, &deadline);
+    assert_eq!(res, Err(Err(ExtendedError::InsufficientAllowance.into())));
+    assert!(client.try_get_escrow_info(&bounty_id).is_err());
+    assert_eq!(token_client.balance(&depositor), 10_000);
+
+    // The lock consumes exactly the amount it pulls from the allowance
+    token_client.approve(
+        &depositor,
+        &client.address,
+        &1_500,
+        &(env.ledger().sequence() + 10_000),
+    );
     client.lock_funds(&depositor, &bounty_id, &1_000, &deadline);
+    assert_eq!(token_client.allowance(&depositor, &client.address), 500);
+}
+
+#[test]
+fn test_batch_lock_funds_checks_allowance_per_depositor_total() {
+    let (env, client, _contract_id) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    token_admin_client.mint(&depositor, &10_000);
+    // Covers each item on its own but not both together
+    token_client.approve(
+        &depositor,
+        &client.address,
+        &1_500,
+        &(env.ledger().sequence() + 10_000),
+    );
+
+    let items = soroban_sdk::vec![
+        &env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: depositor.clone(),
+            amount: 1_000,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: depositor.clone(),
+            amount: 1_000,
+            deadline,
+        },
+    ];
+    let res = client.try_batch_lock_funds(&items);
+    assert_eq!(res, Err(Err(ExtendedError::InsufficientAllowance.into())));
+    assert!(client.try_get_escrow_info(&1).is_err());
+    assert_eq!(token_client.balance(&depositor), 10_000);
+}
+
+#[test]
+fn test_escrow_exists() {
+    let (env, client, _contract_id) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    token_admin_client.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    client.lock_funds(&depositor, &1, &500, &deadline);
+
+    assert!(client.escrow_exists(&1));
+    assert!(!client.escrow_exists(&2));
 }
 
 #[test]
@@ -226,6 +349,7 @@ fn test_refund_allows_exact_deadline_boundary() {
     let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &amount);
+    approve_escrow(&token_client, &depositor, &client.address);
     client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
 
     env.ledger().set_timestamp(deadline);
@@ -252,6 +376,7 @@ fn test_maximum_lock_and_release_path() {
     let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &amount);
+    approve_escrow(&token_client, &depositor, &client.address);
     client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
 
     assert_eq!(token_client.balance(&client.address), amount);
@@ -274,6 +399,7 @@ fn test_integration_multi_bounty_lifecycle() {
     let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &10_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     client.lock_funds(&depositor, &201, &3_000, &(now + 100));
     client.lock_funds(&depositor, &202, &2_000, &(now + 200));
@@ -322,6 +448,9 @@ fn test_multi_token_balance_accounting_isolated_across_escrow_instances() {
 
     token_admin_client_a.mint(&depositor, &5_000);
     token_admin_client_b.mint(&depositor, &7_000);
+    let live_until = env.ledger().sequence() + 10_000;
+    token_client_a.approve(&depositor, &contract_a, &i128::MAX, &live_until);
+    token_client_b.approve(&depositor, &contract_b, &i128::MAX, &live_until);
 
     client_a.lock_funds(&depositor, &11, &1_200, &(now + 120));
     client_b.lock_funds(&depositor, &22, &3_400, &(now + 240));
@@ -363,7 +492,7 @@ fn test_property_fuzz_lock_release_refund_invariants() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
 
     let mut seed = 7_u64;
@@ -376,11 +505,15 @@ fn test_property_fuzz_lock_release_refund_invariants() {
         total_locked += amount;
     }
     token_admin_client.mint(&depositor, &total_locked);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     // Lock deterministic fuzz cases.
     for (id, amount, deadline) in fuzz_cases.iter() {
         client.lock_funds(&depositor, id, amount, deadline);
     }
+    // The test budget accumulates across calls; meter the settle phase on
+    // its own so 80 calls don't exhaust the default limit.
+    env.budget().reset_default();
 
     let mut expected_locked_balance = client.get_balance();
     for i in 0..40_u64 {
@@ -415,6 +548,7 @@ fn test_stress_high_load_bounty_operations() {
     let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &1_000_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     for i in 0..40_u64 {
         let amount = 100 + (i as i128 % 10);
@@ -449,9 +583,10 @@ fn test_gas_proxy_event_footprint_per_operation_is_constant() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &10_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     let before_lock = env.events().all().len();
     for offset in 0..20_u64 {
@@ -971,9 +1106,10 @@ fn test_lock_funds_below_minimum_rejected() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     // Policy: min=100, max=10_000.  Attempting to lock 50 must be rejected.
     client.set_amount_policy(&admin, &100_i128, &10_000_i128);
@@ -992,9 +1128,10 @@ fn test_lock_funds_above_maximum_rejected() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &100_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     // Policy: min=100, max=10_000.  Attempting to lock 50_000 must be rejected.
     client.set_amount_policy(&admin, &100_i128, &10_000_i128);
@@ -1013,9 +1150,10 @@ fn test_lock_funds_at_exact_minimum_succeeds() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     client.set_amount_policy(&admin, &100_i128, &10_000_i128);
     // amount == min → allowed (inclusive lower bound)
@@ -1038,9 +1176,10 @@ fn test_lock_funds_at_exact_maximum_succeeds() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &10_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     client.set_amount_policy(&admin, &100_i128, &10_000_i128);
     // amount == max → allowed (inclusive upper bound)
@@ -1062,9 +1201,10 @@ fn test_lock_funds_within_range_succeeds() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &5_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     client.set_amount_policy(&admin, &100_i128, &10_000_i128);
     client.lock_funds(&depositor, &5, &5_000_i128, &deadline);
@@ -1074,6 +1214,37 @@ fn test_lock_funds_within_range_succeeds() {
     assert_eq!(escrow.status, crate::EscrowStatus::Locked);
 }
 
+/// A whole-token policy is scaled by the token's decimals, so a minimum of
+/// one token rejects half a token.
+#[test]
+fn test_whole_token_amount_policy_scales_by_decimals() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    let one_token = 10_i128.pow(token_client.decimals());
+    token_admin_client.mint(&depositor, &(10 * one_token));
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    client.set_amount_policy_whole_tokens(&admin, &1_i128, &5_i128);
+
+    assert_eq!(
+        client.try_lock_funds(&depositor, &1, &(one_token / 2), &deadline),
+        Err(Ok(ContractError::AmountBelowMinimum))
+    );
+    client.lock_funds(&depositor, &2, &one_token, &deadline);
+    assert_eq!(
+        client.try_lock_funds(&depositor, &3, &(5 * one_token + 1), &deadline),
+        Err(Ok(ContractError::AmountAboveMaximum))
+    );
+}
+
 /// Only the admin may call `set_amount_policy`.  Any other caller must be
 /// rejected with an Unauthorized error.
 #[test]
@@ -1105,9 +1276,10 @@ fn test_no_policy_set_allows_any_positive_amount() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &1_000_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     // No set_amount_policy call — all positive amounts must be accepted.
     client.lock_funds(&depositor, &6, &1_i128, &deadline);
@@ -1146,9 +1318,10 @@ fn test_amount_policy_can_be_updated_by_admin() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &100_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     // First policy: min=1_000 — amount 500 would be rejected here.
     client.set_amount_policy(&admin, &1_000_i128, &50_000_i128);
@@ -1173,9 +1346,10 @@ fn test_one_below_minimum_boundary_rejected() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     client.set_amount_policy(&admin, &100_i128, &10_000_i128);
     // 99 == min(100) - 1 → must be rejected.
@@ -1195,9 +1369,10 @@ fn test_one_above_maximum_boundary_rejected() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &100_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     client.set_amount_policy(&admin, &100_i128, &10_000_i128);
     // 10_001 == max(10_000) + 1 → must be rejected.
@@ -1216,12 +1391,13 @@ fn test_max_bounty_count_queries_accurate() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
 
     const N: u64 = 15;
     let total: i128 = 100 * (N as i128);
     token_admin_client.mint(&depositor, &total);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     for i in 1..=N {
         client.lock_funds(&depositor, &i, &100_i128, &deadline);
@@ -1253,13 +1429,14 @@ fn test_anti_abuse_exact_rate_limit_then_exceeded() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
 
     // Strict config: 2 operations per window, 60s cooldown
     client.update_anti_abuse_config(&3600, &2, &60);
 
     token_admin_client.mint(&depositor, &10_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     // Exactly 2 locks must succeed (different bounty_ids)
     client.lock_funds(&depositor, &1, &100, &deadline);
@@ -1280,12 +1457,13 @@ fn test_anti_abuse_exact_rate_limit_boundary_succeeds() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
 
     client.update_anti_abuse_config(&3600, &3, &60);
 
     token_admin_client.mint(&depositor, &10_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     client.lock_funds(&depositor, &1, &100, &deadline);
     client.lock_funds(&depositor, &2, &100, &deadline);
@@ -1308,13 +1486,14 @@ fn test_anti_abuse_cooldown_violation_panics() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
 
     // Cooldown 100s; we will do second lock at start+50 (within cooldown)
     client.update_anti_abuse_config(&3600, &10, &100);
 
     token_admin_client.mint(&depositor, &10_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     client.lock_funds(&depositor, &1, &100, &deadline);
 
@@ -1335,12 +1514,13 @@ fn test_anti_abuse_after_cooldown_succeeds() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
 
     client.update_anti_abuse_config(&3600, &10, &60);
 
     token_admin_client.mint(&depositor, &10_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     client.lock_funds(&depositor, &1, &100, &deadline);
 
@@ -1361,13 +1541,14 @@ fn test_anti_abuse_whitelisted_address_bypasses_checks() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
 
     client.update_anti_abuse_config(&3600, &2, &60);
     client.set_whitelist_entry(&depositor, &true);
 
     token_admin_client.mint(&depositor, &50_000);
+    approve_escrow(&token_client, &depositor, &client.address);
 
     // More than max_operations without advancing time; whitelisted so all succeed
     for i in 1..=5 {
@@ -1495,3 +1676,1121 @@ fn test_emergency_withdraw() {
     let flags = client.get_pause_flags();
     assert!(flags.lock_paused);
 }
+
+#[test]
+fn test_lock_funds_with_matching_expected_token() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds_with_expected_token(
+        &depositor,
+        &1,
+        &600,
+        &deadline,
+        &Some(token_address.clone()),
+    );
+
+    assert_eq!(client.get_escrow_info(&1).amount, 600);
+    assert_eq!(token_client.balance(&depositor), 400);
+}
+
+#[test]
+fn test_lock_funds_with_mismatched_expected_token_rejected() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    let (other_token, _, _) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    let result = client.try_lock_funds_with_expected_token(
+        &depositor,
+        &1,
+        &600,
+        &deadline,
+        &Some(other_token),
+    );
+
+    assert_eq!(result, Err(Ok(ContractError::UnexpectedToken)));
+    assert_eq!(
+        client.try_get_escrow_info(&1),
+        Err(Ok(ContractError::BountyNotFound))
+    );
+    assert_eq!(token_client.balance(&depositor), 1_000);
+}
+
+#[test]
+fn test_lock_funds_without_expected_token_behaves_like_lock_funds() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds_with_expected_token(&depositor, &1, &500, &deadline, &None);
+
+    assert_eq!(client.get_escrow_info(&1).remaining_amount, 500);
+}
+
+fn funds_locked_events(env: &Env, contract_id: &Address) -> soroban_sdk::Vec<FundsLocked> {
+    let mut found = soroban_sdk::Vec::new(env);
+    for (contract, topics, data) in env.events().all().iter() {
+        if contract != *contract_id {
+            continue;
+        }
+        let topic_0 = Symbol::try_from_val(env, &topics.get(0).unwrap());
+        if topic_0 == Ok(Symbol::new(env, "f_lock")) {
+            found.push_back(FundsLocked::try_from_val(env, &data).unwrap());
+        }
+    }
+    found
+}
+
+#[test]
+fn test_funds_locked_is_topup_false_on_first_lock() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &600, &deadline);
+
+    let events = funds_locked_events(&env, &contract_id);
+    assert_eq!(events.len(), 1);
+    let event = events.get(0).unwrap();
+    assert_eq!(event.amount, 600);
+    assert!(!event.is_topup);
+}
+
+#[test]
+fn test_funds_locked_is_topup_true_on_subsequent_lock() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &600, &deadline);
+    client.top_up_funds(&depositor, &1, &250);
+
+    let events = funds_locked_events(&env, &contract_id);
+    assert_eq!(events.len(), 2);
+    assert!(!events.get(0).unwrap().is_topup);
+    let event = events.get(1).unwrap();
+    assert_eq!(event.bounty_id, 1);
+    assert_eq!(event.amount, 250);
+    assert_eq!(event.deadline, deadline);
+    assert!(event.is_topup);
+
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 850);
+    assert_eq!(escrow.remaining_amount, 850);
+    assert_eq!(token_client.balance(&contract_id), 850);
+}
+
+#[test]
+fn test_top_up_rejects_other_depositor_and_settled_escrow() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+    token_admin.mint(&stranger, &1_000);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &600, &deadline);
+
+    assert_eq!(
+        client.try_top_up_funds(&stranger, &1, &100),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_top_up_funds(&depositor, &2, &100),
+        Err(Ok(ContractError::BountyNotFound))
+    );
+
+    client.release_funds(&1, &contributor);
+    assert_eq!(
+        client.try_top_up_funds(&depositor, &1, &100),
+        Err(Ok(ContractError::FundsNotLocked))
+    );
+}
+
+#[test]
+fn test_third_party_top_ups_refund_pro_rata_on_expiry() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let sponsor_a = Address::generate(&env);
+    let sponsor_b = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+    token_admin.mint(&sponsor_a, &1_000);
+    token_admin.mint(&sponsor_b, &1_000);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &600, &deadline);
+
+    assert_eq!(
+        client.try_top_up_funds(&sponsor_a, &1, &300),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    client.set_allow_third_party_topup(&true);
+    client.top_up_funds(&sponsor_a, &1, &300);
+    client.top_up_funds(&sponsor_b, &1, &100);
+
+    let contributors = client.get_contributors(&1);
+    assert_eq!(contributors.len(), 3);
+    assert_eq!(contributors.get(depositor.clone()), Some(600));
+    assert_eq!(contributors.get(sponsor_a.clone()), Some(300));
+    assert_eq!(contributors.get(sponsor_b.clone()), Some(100));
+
+    // An approved partial refund goes to its named recipient only.
+    client.approve_refund(&1, &100, &depositor, &RefundMode::Partial);
+    client.refund(&1);
+
+    // The remaining 900 is split 6:3:1 on expiry.
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund(&1);
+
+    assert_eq!(token_client.balance(&depositor), 400 + 100 + 540);
+    assert_eq!(token_client.balance(&sponsor_a), 700 + 270);
+    assert_eq!(token_client.balance(&sponsor_b), 900 + 90);
+
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(client.get_refund_history(&1).len(), 4);
+}
+
+#[test]
+fn test_contributors_default_to_depositor_without_top_ups() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &600, &deadline);
+
+    let contributors = client.get_contributors(&1);
+    assert_eq!(contributors.len(), 1);
+    assert_eq!(contributors.get(depositor.clone()), Some(600));
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund(&1);
+    assert_eq!(token_client.balance(&depositor), 1_000);
+}
+
+#[test]
+fn test_min_escrow_lifetime_rejects_immediate_release() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    client.set_min_escrow_lifetime(&3_600);
+    client.lock_funds(&depositor, &1, &1_000, &10_000);
+    assert_eq!(client.get_escrow_created_at(&1), 1_000);
+
+    assert_eq!(
+        client.try_release_funds(&1, &contributor),
+        Err(Ok(ContractError::EscrowTooYoung))
+    );
+
+    env.ledger().set_timestamp(1_000 + 3_599);
+    assert_eq!(
+        client.try_release_funds(&1, &contributor),
+        Err(Ok(ContractError::EscrowTooYoung))
+    );
+    assert_eq!(token_client.balance(&contributor), 0);
+}
+
+#[test]
+fn test_min_escrow_lifetime_allows_release_after_threshold() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    client.set_min_escrow_lifetime(&3_600);
+    client.lock_funds(&depositor, &1, &1_000, &10_000);
+
+    env.ledger().set_timestamp(1_000 + 3_600);
+    client.release_funds(&1, &contributor);
+    assert_eq!(token_client.balance(&contributor), 1_000);
+}
+
+#[test]
+fn test_min_escrow_lifetime_defaults_to_zero() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    assert_eq!(client.get_min_escrow_lifetime(), 0);
+    client.lock_funds(&depositor, &1, &1_000, &(env.ledger().timestamp() + 100));
+    client.release_funds(&1, &contributor);
+}
+
+/// Locks bounty 1 for 1_000 at t=1_000 under a one-hour minimum lifetime.
+fn setup_young_escrow(
+    env: &Env,
+    client: &BountyEscrowContractClient<'static>,
+) -> (Address, Address) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(env);
+    let depositor = Address::generate(env);
+    let contributor = Address::generate(env);
+    let (token_address, token_client, token_admin) = create_token_contract(env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    client.set_min_escrow_lifetime(&3_600);
+    client.lock_funds(&depositor, &1, &1_000, &10_000);
+    (admin, contributor)
+}
+
+#[test]
+fn test_min_escrow_lifetime_rejects_partial_release() {
+    let (env, client, _contract_id) = create_test_env();
+    let (_admin, contributor) = setup_young_escrow(&env, &client);
+
+    assert_eq!(
+        client.try_partial_release(&1, &contributor, &400),
+        Err(Ok(ContractError::EscrowTooYoung))
+    );
+    env.ledger().set_timestamp(1_000 + 3_600);
+    client.partial_release(&1, &contributor, &400);
+}
+
+#[test]
+fn test_min_escrow_lifetime_rejects_batch_release() {
+    let (env, client, _contract_id) = create_test_env();
+    let (_admin, contributor) = setup_young_escrow(&env, &client);
+    let items = soroban_sdk::vec![
+        &env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor,
+        },
+    ];
+
+    assert_eq!(
+        client.try_batch_release_funds(&items),
+        Err(Ok(ContractError::EscrowTooYoung))
+    );
+    env.ledger().set_timestamp(1_000 + 3_600);
+    assert_eq!(client.batch_release_funds(&items), 1);
+}
+
+#[test]
+fn test_min_escrow_lifetime_rejects_capability_release() {
+    let (env, client, _contract_id) = create_test_env();
+    let (admin, contributor) = setup_young_escrow(&env, &client);
+    let holder = Address::generate(&env);
+    let capability_id = client.issue_capability(
+        &admin,
+        &holder,
+        &crate::CapabilityAction::Release,
+        &1,
+        &1_000,
+        &20_000,
+        &1,
+    );
+
+    assert_eq!(
+        client.try_release_with_capability(&1, &contributor, &1_000, &holder, &capability_id),
+        Err(Ok(ContractError::EscrowTooYoung))
+    );
+    env.ledger().set_timestamp(1_000 + 3_600);
+    client.release_with_capability(&1, &contributor, &1_000, &holder, &capability_id);
+}
+
+#[test]
+fn test_min_escrow_lifetime_rejects_reveal_release() {
+    let (env, client, _contract_id) = create_test_env();
+    let (_admin, contributor) = setup_young_escrow(&env, &client);
+    let salt = soroban_sdk::BytesN::from_array(&env, &[3; 32]);
+    let hash = client.compute_release_commitment(&contributor, &1_000, &salt);
+    client.commit_release(&1, &hash);
+
+    assert_eq!(
+        client.try_reveal_release(&1, &contributor, &1_000, &salt),
+        Err(Ok(ContractError::EscrowTooYoung))
+    );
+    env.ledger().set_timestamp(1_000 + 3_600);
+    client.reveal_release(&1, &contributor, &1_000, &salt);
+}
+
+#[test]
+fn test_min_escrow_lifetime_rejects_confirm_release() {
+    let (env, client, _contract_id) = create_test_env();
+    let (_admin, contributor) = setup_young_escrow(&env, &client);
+    client.propose_release(&1, &contributor, &1_000);
+
+    assert_eq!(
+        client.try_confirm_release(&1),
+        Err(Ok(ContractError::EscrowTooYoung))
+    );
+    env.ledger().set_timestamp(1_000 + 3_600);
+    client.confirm_release(&1);
+}
+
+#[test]
+fn test_min_escrow_lifetime_rejects_claim() {
+    let (env, client, _contract_id) = create_test_env();
+    let (_admin, contributor) = setup_young_escrow(&env, &client);
+    client.set_claim_window(&7_200);
+    client.authorize_claim(&1, &contributor, &crate::DisputeReason::Other);
+
+    assert_eq!(client.try_claim(&1), Err(Ok(ContractError::EscrowTooYoung)));
+    env.ledger().set_timestamp(1_000 + 3_600);
+    client.claim(&1);
+}
+
+#[test]
+fn test_min_escrow_lifetime_rejects_claim_ticket() {
+    let (env, client, _contract_id) = create_test_env();
+    let (_admin, contributor) = setup_young_escrow(&env, &client);
+    let ticket_id = client.issue_claim_ticket(&1, &contributor, &1_000, &20_000);
+
+    assert_eq!(
+        client.try_claim_with_ticket(&ticket_id),
+        Err(Ok(ContractError::EscrowTooYoung))
+    );
+    env.ledger().set_timestamp(1_000 + 3_600);
+    client.claim_with_ticket(&ticket_id);
+}
+
+#[test]
+fn test_migrate_backfill_created_at_for_legacy_escrows() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(5_000);
+
+    let admin = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    for bounty_id in 1..=3u64 {
+        // Separate depositors keep the anti-abuse cooldown out of the way.
+        let depositor = Address::generate(&env);
+        token_admin.mint(&depositor, &1_000);
+        approve_escrow(&token_client, &depositor, &client.address);
+        client.lock_funds(&depositor, &bounty_id, &1_000, &10_000);
+    }
+
+    // Escrows 1 and 2 predate creation-time tracking.
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .remove(&crate::DataKey::CreatedAt(1));
+        env.storage()
+            .persistent()
+            .remove(&crate::DataKey::CreatedAt(2));
+    });
+    assert_eq!(client.get_escrow_created_at(&1), 0);
+    assert_eq!(client.get_escrow_info(&1).amount, 1_000);
+
+    // A legacy escrow reads as created at 0, so it is old enough to release.
+    client.set_min_escrow_lifetime(&3_600);
+    client.release_funds(&2, &contributor);
+
+    let backfilled = client.migrate_backfill_created_at(&soroban_sdk::vec![&env, 1, 3, 99], &4_000);
+    assert_eq!(backfilled, 1);
+    assert_eq!(client.get_escrow_created_at(&1), 4_000);
+    // Escrow 3 already had a creation time and is left untouched.
+    assert_eq!(client.get_escrow_created_at(&3), 5_000);
+
+    assert_eq!(
+        client.try_release_funds(&1, &contributor),
+        Err(Ok(ContractError::EscrowTooYoung))
+    );
+    env.ledger().set_timestamp(4_000 + 3_600);
+    client.release_funds(&1, &contributor);
+}
+
+#[test]
+fn test_status_history_records_partial_then_full_release() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    env.ledger().set_timestamp(1_000);
+    client.lock_funds(&depositor, &1, &600, &5_000);
+
+    // A partial release keeps the escrow Locked, so nothing new is recorded.
+    env.ledger().set_timestamp(1_500);
+    client.partial_release(&1, &contributor, &200);
+
+    env.ledger().set_timestamp(2_000);
+    client.partial_release(&1, &contributor, &400);
+
+    let history = client.get_status_history(&1);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap(), (EscrowStatus::Locked, 1_000));
+    assert_eq!(history.get(1).unwrap(), (EscrowStatus::Released, 2_000));
+}
+
+#[test]
+fn test_status_history_records_partial_then_full_refund() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    env.ledger().set_timestamp(1_000);
+    client.lock_funds(&depositor, &1, &600, &5_000);
+
+    env.ledger().set_timestamp(1_200);
+    client.approve_refund(&1, &250, &depositor, &RefundMode::Partial);
+    client.refund(&1);
+
+    env.ledger().set_timestamp(5_000);
+    client.refund(&1);
+
+    let history = client.get_status_history(&1);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(0).unwrap(), (EscrowStatus::Locked, 1_000));
+    assert_eq!(
+        history.get(1).unwrap(),
+        (EscrowStatus::PartiallyRefunded, 1_200)
+    );
+    assert_eq!(history.get(2).unwrap(), (EscrowStatus::Refunded, 5_000));
+}
+
+#[test]
+fn test_status_history_unknown_bounty() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (token_address, _token_client, _token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+
+    assert_eq!(
+        client.try_get_status_history(&42),
+        Err(Ok(ContractError::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_client_ref_round_trips_into_lock_and_release_events() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let lock_ref = soroban_sdk::String::from_str(&env, "job-42/lock");
+    let release_ref = soroban_sdk::String::from_str(&env, "job-42/release");
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds_with_ref(&depositor, &1, &600, &deadline, &Some(lock_ref.clone()));
+    client.release_funds_with_ref(&1, &contributor, &Some(release_ref.clone()));
+
+    let locked = funds_locked_events(&env, &contract_id);
+    assert_eq!(locked.get(0).unwrap().client_ref, Some(lock_ref));
+
+    let mut released = None;
+    for (contract, topics, data) in env.events().all().iter() {
+        let topic_0 = Symbol::try_from_val(&env, &topics.get(0).unwrap());
+        if contract == contract_id && topic_0 == Ok(Symbol::new(&env, "f_rel")) {
+            released = Some(FundsReleased::try_from_val(&env, &data).unwrap());
+        }
+    }
+    assert_eq!(released.unwrap().client_ref, Some(release_ref));
+}
+
+#[test]
+fn test_release_and_refund_events_carry_escrow_deadline() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &400, &deadline);
+    client.lock_funds(&depositor, &2, &600, &deadline);
+    client.release_funds(&1, &contributor);
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund(&2);
+
+    let mut released = None;
+    let mut refunded = None;
+    for (contract, topics, data) in env.events().all().iter() {
+        if contract != contract_id {
+            continue;
+        }
+        let topic_0 = Symbol::try_from_val(&env, &topics.get(0).unwrap());
+        if topic_0 == Ok(Symbol::new(&env, "f_rel")) {
+            released = Some(FundsReleased::try_from_val(&env, &data).unwrap());
+        } else if topic_0 == Ok(Symbol::new(&env, "f_ref")) {
+            refunded = Some(FundsRefunded::try_from_val(&env, &data).unwrap());
+        }
+    }
+
+    let released = released.unwrap();
+    assert_eq!(released.bounty_id, 1);
+    assert_eq!(released.deadline, client.get_escrow_info(&1).deadline);
+    let refunded = refunded.unwrap();
+    assert_eq!(refunded.bounty_id, 2);
+    assert_eq!(refunded.deadline, client.get_escrow_info(&2).deadline);
+}
+
+#[test]
+fn test_event_seq_strictly_increases_within_one_ledger() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    let last_seq = |env: &Env| -> u64 {
+        let mut seq = 0;
+        for (contract, topics, data) in env.events().all().iter() {
+            if contract != contract_id {
+                continue;
+            }
+            let topic_0 = Symbol::try_from_val(env, &topics.get(0).unwrap());
+            if topic_0 == Ok(Symbol::new(env, "f_lock")) {
+                seq = FundsLocked::try_from_val(env, &data).unwrap().event_seq;
+            } else if topic_0 == Ok(Symbol::new(env, "f_rel")) {
+                seq = FundsReleased::try_from_val(env, &data).unwrap().event_seq;
+            }
+        }
+        seq
+    };
+
+    client.lock_funds(&depositor, &1, &400, &deadline);
+    let first = last_seq(&env);
+    client.lock_funds(&depositor, &2, &600, &deadline);
+    let second = last_seq(&env);
+    client.release_funds(&1, &contributor);
+    let third = last_seq(&env);
+
+    assert!(first < second, "{} !< {}", first, second);
+    assert!(second < third, "{} !< {}", second, third);
+}
+
+#[test]
+fn test_lock_funds_without_ref_emits_none() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &600, &deadline);
+
+    assert_eq!(
+        funds_locked_events(&env, &contract_id)
+            .get(0)
+            .unwrap()
+            .client_ref,
+        None
+    );
+}
+
+#[test]
+fn test_over_long_client_ref_rejected() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let too_long = soroban_sdk::String::from_bytes(&env, &[b'r'; 65]);
+    let deadline = env.ledger().timestamp() + 1_000;
+    assert_eq!(
+        client.try_lock_funds_with_ref(&depositor, &1, &600, &deadline, &Some(too_long.clone())),
+        Err(Ok(ContractError::ClientRefTooLong))
+    );
+
+    client.lock_funds(&depositor, &1, &600, &deadline);
+    assert_eq!(
+        client.try_release_funds_with_ref(&1, &contributor, &Some(too_long)),
+        Err(Ok(ContractError::ClientRefTooLong))
+    );
+}
+
+#[test]
+fn test_batch_release_after_partial_release_pays_remaining() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    let items = soroban_sdk::vec![
+        &env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: depositor.clone(),
+            amount: 600,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: depositor.clone(),
+            amount: 400,
+            deadline,
+        },
+    ];
+    client.batch_lock_funds(&items);
+
+    // Partial releases keep the escrow Locked with a reduced remaining_amount
+    client.partial_release(&1, &contributor, &200);
+    assert_eq!(client.get_escrow_info(&1).status, EscrowStatus::Locked);
+
+    let items = soroban_sdk::vec![
+        &env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: contributor.clone(),
+        },
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: contributor.clone(),
+        },
+    ];
+    assert_eq!(client.batch_release_funds(&items), 2);
+
+    assert_eq!(token_client.balance(&contributor), 1_000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+}
+
+#[test]
+fn test_release_funds_split_pays_three_recipients() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+    client.lock_funds(&depositor, &1, &1_000, &(env.ledger().timestamp() + 1_000));
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let recipients = soroban_sdk::vec![&env, alice.clone(), bob.clone(), carol.clone()];
+
+    client.release_funds_split(
+        &1,
+        &recipients,
+        &soroban_sdk::vec![&env, 200, 300, 100],
+        &Some(token_address.clone()),
+    );
+    assert_eq!(token_client.balance(&alice), 200);
+    assert_eq!(token_client.balance(&bob), 300);
+    assert_eq!(token_client.balance(&carol), 100);
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.remaining_amount, 400);
+
+    client.release_funds_split(
+        &1,
+        &recipients,
+        &soroban_sdk::vec![&env, 100, 100, 200],
+        &None,
+    );
+    assert_eq!(token_client.balance(&carol), 300);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    let escrow = client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+}
+
+#[test]
+fn test_release_funds_split_rejects_sum_above_remaining() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+    client.lock_funds(&depositor, &1, &1_000, &(env.ledger().timestamp() + 1_000));
+
+    let recipients = soroban_sdk::vec![&env, Address::generate(&env), Address::generate(&env)];
+    let res =
+        client.try_release_funds_split(&1, &recipients, &soroban_sdk::vec![&env, 600, 401], &None);
+    assert_eq!(res, Err(Ok(ContractError::InsufficientFunds)));
+
+    let res = client.try_release_funds_split(&1, &recipients, &soroban_sdk::vec![&env, 600], &None);
+    assert_eq!(res, Err(Ok(ContractError::BatchSizeMismatch)));
+
+    assert_eq!(token_client.balance(&contract_id), 1_000);
+    assert_eq!(client.get_escrow_info(&1).remaining_amount, 1_000);
+}
+
+#[test]
+fn test_init_records_contract_version() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let (token_address, _token_client, _token_admin) = create_token_contract(&env, &admin);
+
+    client.init(&admin, &token_address);
+
+    assert_eq!(client.get_version(), crate::CONTRACT_VERSION);
+}
+
+#[test]
+fn test_migrate_updates_version_and_emits_event() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let (token_address, _token_client, _token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+
+    client.migrate(&2);
+
+    assert_eq!(client.get_version(), 2);
+    let (emitter, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(emitter, contract_id);
+    let topic_0: Symbol = Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+    assert_eq!(topic_0, Symbol::new(&env, "migrated"));
+    let event = crate::events::ContractMigrated::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.from_version, crate::CONTRACT_VERSION);
+    assert_eq!(event.to_version, 2);
+    assert_eq!(event.admin, admin);
+}
+
+#[test]
+fn test_migrate_rejects_lower_version() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let (token_address, _token_client, _token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+
+    client.migrate(&3);
+
+    assert_eq!(
+        client.try_migrate(&2),
+        Err(Ok(ContractError::InvalidVersion))
+    );
+    assert_eq!(client.get_version(), 3);
+}
+
+#[test]
+#[should_panic(expected = "InvalidAction")]
+fn test_migrate_requires_admin_auth() {
+    let (env, client, contract_id) = create_test_env();
+    let admin = Address::generate(&env);
+    let non_admin = Address::generate(&env);
+    env.mock_all_auths();
+    let (token_address, _token_client, _token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+
+    env.mock_auths(&[MockAuth {
+        address: &non_admin,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "migrate",
+            args: (2u32,).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.migrate(&2);
+}
+
+#[test]
+fn test_max_releases_per_bounty_rejects_third_partial_release() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+    client.set_max_releases_per_bounty(&2);
+    assert_eq!(client.get_max_releases_per_bounty(), 2);
+
+    client.partial_release(&1, &contributor, &100);
+    client.partial_release(&1, &contributor, &100);
+    assert_eq!(client.get_release_count(&1), 2);
+
+    let res = client.try_partial_release(&1, &contributor, &100);
+    assert_eq!(res, Err(Err(ExtendedError::ReleaseLimitReached.into())));
+    assert_eq!(client.get_release_count(&1), 2);
+    assert_eq!(client.get_escrow_info(&1).remaining_amount, 800);
+    assert_eq!(token_client.balance(&contributor), 200);
+}
+
+#[test]
+fn test_max_releases_per_bounty_applies_to_split_release() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+    client.set_max_releases_per_bounty(&1);
+    client.partial_release(&1, &contributor, &100);
+
+    let recipients = soroban_sdk::vec![&env, contributor.clone()];
+    let res = client.try_release_funds_split(&1, &recipients, &soroban_sdk::vec![&env, 100], &None);
+    assert_eq!(res, Err(Err(ExtendedError::ReleaseLimitReached.into())));
+    assert_eq!(client.get_escrow_info(&1).remaining_amount, 900);
+    assert_eq!(token_client.balance(&contributor), 100);
+}
+
+#[test]
+fn test_lock_classic_asset_resolves_stellar_asset_contract() {
+    let (env, client, contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_client = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &contract_id);
+    client.init(&admin, &sac.address());
+
+    // The test helper issues the asset under the code "aaa"
+    let issuer = sac.issuer().address();
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_classic_asset(
+        &depositor,
+        &1,
+        &issuer,
+        &String::from_str(&env, "aaa"),
+        &600,
+        &deadline,
+    );
+
+    let info = client.get_escrow_info(&1);
+    assert_eq!(info.amount, 600);
+    assert_eq!(info.status, EscrowStatus::Locked);
+    assert_eq!(token_client.balance(&contract_id), 600);
+
+    // A different code resolves to a different contract
+    let res = client.try_lock_classic_asset(
+        &depositor,
+        &2,
+        &issuer,
+        &String::from_str(&env, "bbb"),
+        &100,
+        &deadline,
+    );
+    assert_eq!(res, Err(Ok(ContractError::UnexpectedToken)));
+
+    let res = client.try_lock_classic_asset(
+        &depositor,
+        &2,
+        &contract_id,
+        &String::from_str(&env, "aaa"),
+        &100,
+        &deadline,
+    );
+    assert_eq!(res, Err(Ok(ContractError::InvalidAssetId)));
+}
+
+#[test]
+fn test_escrow_summary_matches_full_record() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &600, &deadline);
+    client.lock_funds(&depositor, &2, &400, &(deadline + 50));
+    client.partial_release(&1, &contributor, &250);
+
+    let info = client.get_escrow_info(&1);
+    let summary = client.get_escrow_summary(&1);
+    assert_eq!(summary.bounty_id, 1);
+    assert_eq!(summary.depositor, info.depositor);
+    assert_eq!(summary.amount, info.amount);
+    assert_eq!(summary.remaining_amount, info.remaining_amount);
+    assert_eq!(summary.remaining_amount, 350);
+    assert_eq!(summary.status, info.status);
+    assert_eq!(summary.deadline, info.deadline);
+    assert_eq!(summary.token_address, token_address);
+
+    assert_eq!(
+        client.try_get_escrow_summary(&99),
+        Err(Ok(ContractError::BountyNotFound))
+    );
+
+    // Unknown ids are skipped in the batched view
+    let summaries = client.get_escrow_summaries(&soroban_sdk::vec![&env, 2_u64, 99, 1]);
+    assert_eq!(summaries.len(), 2);
+    assert_eq!(summaries.get(0).unwrap(), client.get_escrow_summary(&2));
+    assert_eq!(summaries.get(1).unwrap(), summary);
+}
+
+#[test]
+fn test_deadline_limits_reject_short_and_long_deadlines() {
+    let (env, client, _contract_id) = create_test_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    client.init(&admin, &token_address);
+    token_admin.mint(&depositor, &1_000);
+    approve_escrow(&token_client, &depositor, &client.address);
+
+    client.set_deadline_limits(&3_600, &86_400);
+    assert_eq!(client.get_deadline_limits(), (3_600, 86_400));
+    let now = env.ledger().timestamp();
+
+    let res = client.try_lock_funds(&depositor, &1, &100, &(now + 600));
+    assert_eq!(res, Err(Ok(ContractError::InvalidDeadline)));
+
+    let res = client.try_lock_funds(&depositor, &1, &100, &(now + 86_401));
+    assert_eq!(res, Err(Ok(ContractError::InvalidDeadline)));
+    assert!(!client.escrow_exists(&1));
+
+    client.lock_funds(&depositor, &1, &100, &(now + 7_200));
+    assert_eq!(client.get_escrow_info(&1).status, EscrowStatus::Locked);
+
+    // Amount bounds come from the amount policy
+    client.set_amount_policy(&admin, &50, &500);
+    let res = client.try_lock_funds(&depositor, &2, &600, &(now + 7_200));
+    assert_eq!(res, Err(Ok(ContractError::AmountAboveMaximum)));
+}
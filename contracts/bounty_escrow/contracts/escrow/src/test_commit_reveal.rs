@@ -0,0 +1,254 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env,
+};
+
+struct Setup<'a> {
+    env: Env,
+    depositor: Address,
+    contributor: Address,
+    token: token::Client<'a>,
+    escrow: BountyEscrowContractClient<'a>,
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let contributor = Address::generate(&env);
+
+        let token_addr = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let token = token::Client::new(&env, &token_addr);
+        token::StellarAssetClient::new(&env, &token_addr).mint(&depositor, &10_000);
+
+        let contract_id = env.register_contract(None, BountyEscrowContract);
+        let escrow = BountyEscrowContractClient::new(&env, &contract_id);
+        escrow.init(&admin, &token_addr);
+        token.approve(
+            &depositor,
+            &contract_id,
+            &i128::MAX,
+            &(env.ledger().sequence() + 10_000),
+        );
+
+        let deadline = env.ledger().timestamp() + 100_000;
+        escrow.lock_funds(&depositor, &1, &1_000, &deadline);
+
+        Self {
+            env,
+            depositor,
+            contributor,
+            token,
+            escrow,
+        }
+    }
+
+    fn salt(&self, byte: u8) -> BytesN<32> {
+        BytesN::from_array(&self.env, &[byte; 32])
+    }
+}
+
+#[test]
+fn test_commit_then_reveal_after_delay_releases() {
+    let s = Setup::new();
+    s.escrow.set_reveal_delay(&3_600);
+
+    let salt = s.salt(7);
+    let hash = s
+        .escrow
+        .compute_release_commitment(&s.contributor, &1_000, &salt);
+    s.escrow.commit_release(&1, &hash);
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 3_600);
+    s.escrow.reveal_release(&1, &s.contributor, &1_000, &salt);
+
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Released);
+    assert_eq!(info.remaining_amount, 0);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_reveal_before_delay_rejected() {
+    let s = Setup::new();
+    s.escrow.set_reveal_delay(&3_600);
+
+    let salt = s.salt(7);
+    let hash = s
+        .escrow
+        .compute_release_commitment(&s.contributor, &1_000, &salt);
+    s.escrow.commit_release(&1, &hash);
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 3_599);
+    assert_eq!(
+        s.escrow
+            .try_reveal_release(&1, &s.contributor, &1_000, &salt),
+        Err(Ok(Error::RevealTooEarly))
+    );
+    assert_eq!(s.token.balance(&s.contributor), 0);
+}
+
+#[test]
+fn test_mismatched_reveal_rejected() {
+    let s = Setup::new();
+    let salt = s.salt(7);
+    let hash = s
+        .escrow
+        .compute_release_commitment(&s.contributor, &1_000, &salt);
+    s.escrow.commit_release(&1, &hash);
+
+    // Different recipient
+    assert_eq!(
+        s.escrow.try_reveal_release(&1, &s.depositor, &1_000, &salt),
+        Err(Ok(Error::CommitmentMismatch))
+    );
+    // Different amount
+    assert_eq!(
+        s.escrow.try_reveal_release(&1, &s.contributor, &999, &salt),
+        Err(Ok(Error::CommitmentMismatch))
+    );
+    // Different salt
+    assert_eq!(
+        s.escrow
+            .try_reveal_release(&1, &s.contributor, &1_000, &s.salt(8)),
+        Err(Ok(Error::CommitmentMismatch))
+    );
+
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_reveal_without_commit_rejected_and_commitment_single_use() {
+    let s = Setup::new();
+    let salt = s.salt(1);
+    assert_eq!(
+        s.escrow.try_reveal_release(&1, &s.contributor, &400, &salt),
+        Err(Ok(Error::CommitmentNotFound))
+    );
+
+    let hash = s
+        .escrow
+        .compute_release_commitment(&s.contributor, &400, &salt);
+    s.escrow.commit_release(&1, &hash);
+    s.escrow.reveal_release(&1, &s.contributor, &400, &salt);
+    assert_eq!(s.escrow.get_escrow_info(&1).remaining_amount, 600);
+
+    assert_eq!(
+        s.escrow.try_reveal_release(&1, &s.contributor, &400, &salt),
+        Err(Ok(Error::CommitmentNotFound))
+    );
+}
+
+#[test]
+fn test_reveal_delay_blocks_direct_releases() {
+    let s = Setup::new();
+    s.escrow.set_reveal_delay(&3_600);
+
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::CommitmentNotFound))
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &400),
+        Err(Ok(Error::CommitmentNotFound))
+    );
+    assert_eq!(
+        s.escrow.try_release_funds_split(
+            &1,
+            &vec![&s.env, s.contributor.clone()],
+            &vec![&s.env, 1_000_i128],
+            &None,
+        ),
+        Err(Ok(Error::CommitmentNotFound))
+    );
+    assert_eq!(
+        s.escrow.try_batch_release_funds(&vec![
+            &s.env,
+            ReleaseFundsItem {
+                bounty_id: 1,
+                contributor: s.contributor.clone(),
+            },
+        ]),
+        Err(Ok(Error::CommitmentNotFound))
+    );
+    assert_eq!(s.token.balance(&s.contributor), 0);
+
+    // The two-step path still pays out
+    let salt = s.salt(3);
+    let hash = s
+        .escrow
+        .compute_release_commitment(&s.contributor, &1_000, &salt);
+    s.escrow.commit_release(&1, &hash);
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 3_600);
+    s.escrow.reveal_release(&1, &s.contributor, &1_000, &salt);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_zero_reveal_delay_allows_direct_release() {
+    let s = Setup::new();
+    s.escrow.set_reveal_delay(&3_600);
+    s.escrow.set_reveal_delay(&0);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_reveal_delay_blocks_authorized_claim() {
+    let s = Setup::new();
+    s.escrow
+        .authorize_claim(&1, &s.contributor, &DisputeReason::Other);
+    s.escrow.set_reveal_delay(&3_600);
+
+    assert_eq!(s.escrow.try_claim(&1), Err(Ok(Error::CommitmentNotFound)));
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_reveal_delay_blocks_claim_ticket() {
+    let s = Setup::new();
+    let expires_at = s.env.ledger().timestamp() + 1_000;
+    let ticket_id = s
+        .escrow
+        .issue_claim_ticket(&1, &s.contributor, &1_000, &expires_at);
+    s.escrow.set_reveal_delay(&3_600);
+
+    assert_eq!(
+        s.escrow.try_claim_with_ticket(&ticket_id),
+        Err(Ok(Error::CommitmentNotFound))
+    );
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_reveal_delay_blocks_dispute_payout() {
+    let s = Setup::new();
+    s.escrow.set_reveal_delay(&3_600);
+    s.escrow.open_dispute(&1);
+
+    assert_eq!(
+        s.escrow.try_resolve_dispute(
+            &1,
+            &DisputeOutcome::ResolvedByPayout,
+            &Some(s.contributor.clone()),
+        ),
+        Err(Ok(Error::CommitmentNotFound))
+    );
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Disputed);
+}
@@ -6,7 +6,7 @@
 //! These tests ensure that contract changes maintain backward compatibility
 //! according to the policy defined in BACKWARDS_COMPATIBILITY_POLICY.md
 
-use crate::{BountyEscrowContract, BountyEscrowContractClient, Error};
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, ExtendedError};
 use soroban_sdk::{testutils::Address as _, token, Address, Env};
 
 fn create_token_contract<'a>(
@@ -20,6 +20,16 @@ fn create_token_contract<'a>(
     (token, token_client, token_admin_client)
 }
 
+/// Approves `escrow` to pull any amount of `from`'s tokens, which `lock_funds` needs.
+fn approve_escrow(token: &token::Client, from: &Address, escrow: &Address) {
+    token.approve(
+        from,
+        escrow,
+        &i128::MAX,
+        &(token.env.ledger().sequence() + 10_000),
+    );
+}
+
 /// Test that all error codes remain stable
 #[test]
 fn test_error_codes_are_stable() {
@@ -46,6 +56,12 @@ fn test_error_codes_are_stable() {
     assert_eq!(Error::AmountBelowMinimum as u32, 19);
     assert_eq!(Error::AmountAboveMaximum as u32, 20);
     assert_eq!(Error::NotPaused as u32, 21);
+
+    // Errors past the spec's variant limit live in their own enum.
+    assert_eq!(ExtendedError::HighValueReleaseRequired as u32, 100);
+    assert_eq!(ExtendedError::InsufficientAllowance as u32, 101);
+    assert_eq!(ExtendedError::MetadataTooLarge as u32, 102);
+    assert_eq!(ExtendedError::ReleaseLimitReached as u32, 103);
 }
 
 /// Test that core function signatures haven't changed
@@ -62,7 +78,7 @@ fn test_core_function_signatures_stable() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
     // These function calls should compile without errors.
     // If signatures change, this test will fail to compile.
@@ -70,6 +86,7 @@ fn test_core_function_signatures_stable() {
     // Core functions
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &1000);
+    approve_escrow(&token_client, &depositor, &client.address);
     client.lock_funds(&depositor, &1, &1000, &100);
     client.release_funds(&1, &contributor);
 
@@ -92,11 +109,12 @@ fn test_storage_keys_accessible() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
     // Initialize and create escrow
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &1000);
+    approve_escrow(&token_client, &depositor, &client.address);
     client.lock_funds(&depositor, &1, &1000, &100);
 
     // Verify we can still access stored data
@@ -125,11 +143,12 @@ fn test_old_client_compatibility() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
     // Simulate old client code that doesn't use new features
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &1000);
+    approve_escrow(&token_client, &depositor, &client.address);
     client.lock_funds(&depositor, &1, &1000, &100);
     client.release_funds(&1, &contributor);
 
@@ -177,11 +196,12 @@ fn test_new_features_dont_break_existing_workflows() {
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
 
     // Standard workflow without using new features
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &1000);
+    approve_escrow(&token_client, &depositor, &client.address);
     client.lock_funds(&depositor, &1, &1000, &100);
 
     // New feature: amount policy (should not affect existing escrows)
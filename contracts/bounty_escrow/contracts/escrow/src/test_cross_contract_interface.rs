@@ -18,6 +18,16 @@ mod cross_contract_interface_tests {
         )
     }
 
+    /// Approves `escrow` to pull any amount of `from`'s tokens, which `lock_funds` needs.
+    fn approve_escrow(token: &token::Client, from: &Address, escrow: &Address) {
+        token.approve(
+            from,
+            escrow,
+            &i128::MAX,
+            &(token.env.ledger().sequence() + 10_000),
+        );
+    }
+
     #[test]
     fn test_escrow_interface_lock_funds_via_trait() {
         let env = Env::default();
@@ -34,6 +44,7 @@ mod cross_contract_interface_tests {
 
         // Mint tokens to depositor
         token_admin.mint(&depositor, &1_000_000);
+        approve_escrow(&token, &depositor, &client.address);
 
         let bounty_id = 1u64;
         let amount = 1000i128;
@@ -65,6 +76,7 @@ mod cross_contract_interface_tests {
 
         // Mint tokens to depositor
         token_admin.mint(&depositor, &1_000_000);
+        approve_escrow(&token, &depositor, &client.address);
 
         let bounty_id = 1u64;
         let amount = 1000i128;
@@ -96,6 +108,7 @@ mod cross_contract_interface_tests {
 
         // Mint tokens to depositor
         token_admin.mint(&depositor, &1_000_000);
+        approve_escrow(&token, &depositor, &client.address);
 
         let bounty_id = 1u64;
         let amount = 1000i128;
@@ -131,6 +144,7 @@ mod cross_contract_interface_tests {
 
         // Mint tokens to depositor
         token_admin.mint(&depositor, &1_000_000);
+        approve_escrow(&token, &depositor, &client.address);
 
         let bounty_id = 1u64;
         let amount = 5000i128;
@@ -169,6 +183,7 @@ mod cross_contract_interface_tests {
 
         // Mint tokens to depositor
         token_admin.mint(&depositor, &1_000_000);
+        approve_escrow(&token, &depositor, &client.address);
 
         let bounty_id = 1u64;
         let amount = 1000i128;
@@ -198,6 +213,7 @@ mod cross_contract_interface_tests {
 
         // Mint tokens to depositor
         token_admin.mint(&depositor, &1_000_000);
+        approve_escrow(&token, &depositor, &client.address);
 
         let bounty_id = 1u64;
         let amount = 1000i128;
@@ -228,6 +244,7 @@ mod cross_contract_interface_tests {
 
         // Mint tokens to depositor
         token_admin.mint(&depositor, &1_000_000);
+        approve_escrow(&token, &depositor, &client.address);
 
         let bounty_id = 1u64;
         let amount = 1000i128;
@@ -262,6 +279,7 @@ mod cross_contract_interface_tests {
 
         // Mint tokens to depositor
         token_admin.mint(&depositor, &1_000_000);
+        approve_escrow(&token, &depositor, &client.address);
 
         let bounty_id = 1u64;
         let amount = 1000i128;
@@ -294,6 +312,7 @@ mod cross_contract_interface_tests {
 
         // Mint tokens to depositor
         token_admin.mint(&depositor, &1_000_000);
+        approve_escrow(&token, &depositor, &client.address);
 
         let deadline = env.ledger().timestamp() + 3600;
 
@@ -46,6 +46,12 @@ impl<'a> Setup<'a> {
 
         escrow.init(&admin, &token.address);
         token_admin.mint(&depositor, &10_000_000);
+        token.approve(
+            &depositor,
+            &escrow.address,
+            &i128::MAX,
+            &(env.ledger().sequence() + 10_000),
+        );
 
         Self {
             env,
@@ -49,6 +49,12 @@ impl<'a> DisputeTestSetup<'a> {
 
         escrow.init(&admin, &token.address);
         token_admin.mint(&depositor, &10_000_000);
+        token.approve(
+            &depositor,
+            &escrow.address,
+            &i128::MAX,
+            &(env.ledger().sequence() + 10_000),
+        );
 
         Self {
             env,
@@ -202,3 +208,172 @@ fn test_resolve_dispute_in_favor_of_refund() {
     assert_eq!(setup.token.balance(&setup.depositor), 10_000_000);
     assert_eq!(setup.token.balance(&setup.escrow.address), 0);
 }
+
+#[test]
+fn test_disputed_escrow_blocks_expired_refund_until_resolved() {
+    let setup = DisputeTestSetup::new();
+    let bounty_id = 65_u64;
+    let amount = 1_000_i128;
+    let deadline = setup.env.ledger().timestamp() + 300;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.open_dispute(&bounty_id);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&bounty_id).status,
+        EscrowStatus::Disputed
+    );
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    let (can_refund, deadline_passed, _, _) = setup.escrow.get_refund_eligibility(&bounty_id);
+    assert!(!can_refund);
+    assert!(deadline_passed);
+    assert_eq!(
+        setup.escrow.try_refund(&bounty_id),
+        Err(Ok(Error::EscrowDisputed))
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_funds(&bounty_id, &setup.contributor),
+        Err(Ok(Error::EscrowDisputed))
+    );
+
+    setup
+        .escrow
+        .resolve_dispute(&bounty_id, &DisputeOutcome::NoActionTaken, &None);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&bounty_id).status,
+        EscrowStatus::Locked
+    );
+    assert!(setup.escrow.get_refund_eligibility(&bounty_id).0);
+
+    setup.escrow.refund(&bounty_id);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&bounty_id).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(setup.token.balance(&setup.depositor), 10_000_000);
+}
+
+#[test]
+fn test_resolve_hold_with_payout_releases_to_recipient() {
+    let setup = DisputeTestSetup::new();
+    let bounty_id = 66_u64;
+    let amount = 2_500_i128;
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.open_dispute(&bounty_id);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_resolve_dispute(&bounty_id, &DisputeOutcome::ResolvedByPayout, &None),
+        Err(Ok(Error::DisputeRecipientRequired))
+    );
+
+    setup.escrow.resolve_dispute(
+        &bounty_id,
+        &DisputeOutcome::ResolvedByPayout,
+        &Some(setup.contributor.clone()),
+    );
+
+    assert_eq!(
+        setup.escrow.get_escrow_info(&bounty_id).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), amount);
+    assert_eq!(
+        setup
+            .escrow
+            .try_resolve_dispute(&bounty_id, &DisputeOutcome::NoActionTaken, &None),
+        Err(Ok(Error::NotDisputed))
+    );
+}
+
+#[test]
+fn test_resolve_hold_with_refund_before_deadline() {
+    let setup = DisputeTestSetup::new();
+    let bounty_id = 67_u64;
+    let amount = 4_000_i128;
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.open_dispute(&bounty_id);
+    setup
+        .escrow
+        .resolve_dispute(&bounty_id, &DisputeOutcome::ResolvedByRefund, &None);
+
+    assert_eq!(
+        setup.escrow.get_escrow_info(&bounty_id).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(setup.token.balance(&setup.depositor), 10_000_000);
+    assert_eq!(setup.token.balance(&setup.escrow.address), 0);
+}
+
+#[test]
+fn test_emergency_withdraw_zeroes_disputed_escrow() {
+    let setup = DisputeTestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &68, &3_000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &69, &1_000, &deadline);
+    setup.escrow.open_dispute(&68);
+    assert_eq!(setup.escrow.get_active_escrow_count(), 2);
+
+    setup.escrow.set_paused(&Some(true), &None, &None, &None);
+    let target = Address::generate(&setup.env);
+    setup.escrow.emergency_withdraw(&target);
+
+    assert_eq!(setup.token.balance(&target), 4_000);
+    for bounty_id in [68_u64, 69] {
+        let escrow = setup.escrow.get_escrow_info(&bounty_id);
+        assert_eq!(escrow.status, EscrowStatus::Refunded);
+        assert_eq!(escrow.remaining_amount, 0);
+    }
+    assert_eq!(setup.escrow.get_active_escrow_count(), 0);
+    assert_eq!(
+        setup
+            .escrow
+            .try_resolve_dispute(&68, &DisputeOutcome::NoActionTaken, &None),
+        Err(Ok(Error::NotDisputed))
+    );
+}
+
+#[test]
+fn test_authorized_claim_cannot_release_disputed_escrow() {
+    let setup = DisputeTestSetup::new();
+    let bounty_id = 70_u64;
+    let amount = 1_500_i128;
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup
+        .escrow
+        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::Other);
+    setup.escrow.open_dispute(&bounty_id);
+
+    assert_eq!(
+        setup.escrow.try_claim(&bounty_id),
+        Err(Ok(Error::EscrowDisputed))
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&bounty_id).status,
+        EscrowStatus::Disputed
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+    assert_eq!(setup.token.balance(&setup.escrow.address), amount);
+}
@@ -56,6 +56,12 @@ impl TestContext {
 
         // Mint tokens to depositor
         token_sac.mint(&depositor, &1_000_000);
+        token::Client::new(&env, &token_sac.address).approve(
+            &depositor,
+            &client.address,
+            &i128::MAX,
+            &(env.ledger().sequence() + 10_000),
+        );
 
         Self {
             env,
@@ -52,6 +52,12 @@ impl<'a> TestSetup<'a> {
 
         // Mint tokens to depositor
         token_admin.mint(&depositor, &10_000_000);
+        token.approve(
+            &depositor,
+            &escrow.address,
+            &i128::MAX,
+            &(env.ledger().sequence() + 10_000),
+        );
 
         Self {
             env,
@@ -45,6 +45,12 @@ impl<'a> TestSetup<'a> {
         let escrow = create_escrow_contract(&env);
         escrow.init(&admin, &token.address);
         token_admin.mint(&depositor, &1_000_000);
+        token.approve(
+            &depositor,
+            &escrow.address,
+            &i128::MAX,
+            &(env.ledger().sequence() + 10_000),
+        );
 
         Self {
             env,
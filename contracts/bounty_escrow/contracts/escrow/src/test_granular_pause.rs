@@ -68,6 +68,12 @@ fn setup(
 
     escrow_client.init(&admin, &token_client.address);
     token_sac.mint(&depositor, &depositor_balance);
+    token_client.approve(
+        &depositor,
+        &escrow_client.address,
+        &i128::MAX,
+        &(env.ledger().sequence() + 10_000),
+    );
 
     (escrow_client, admin, depositor, token_client)
 }
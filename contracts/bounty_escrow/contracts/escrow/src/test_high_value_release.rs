@@ -0,0 +1,219 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env,
+};
+
+struct Setup<'a> {
+    env: Env,
+    admin: Address,
+    contributor: Address,
+    token: token::Client<'a>,
+    escrow: BountyEscrowContractClient<'a>,
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let contributor = Address::generate(&env);
+
+        let token_addr = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let token = token::Client::new(&env, &token_addr);
+        token::StellarAssetClient::new(&env, &token_addr).mint(&depositor, &10_000);
+
+        let contract_id = env.register_contract(None, BountyEscrowContract);
+        let escrow = BountyEscrowContractClient::new(&env, &contract_id);
+        escrow.init(&admin, &token_addr);
+        token.approve(
+            &depositor,
+            &contract_id,
+            &i128::MAX,
+            &(env.ledger().sequence() + 10_000),
+        );
+
+        let deadline = env.ledger().timestamp() + 100_000;
+        escrow.lock_funds(&depositor, &1, &5_000, &deadline);
+        escrow.lock_funds(&depositor, &2, &500, &deadline);
+        escrow.set_high_value_release_config(&1_000, &3_600);
+
+        Self {
+            env,
+            admin,
+            contributor,
+            token,
+            escrow,
+        }
+    }
+}
+
+#[test]
+fn test_propose_wait_confirm_releases() {
+    let s = Setup::new();
+
+    s.escrow.propose_release(&1, &s.contributor, &5_000);
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 3_600);
+    s.escrow.confirm_release(&1);
+
+    assert_eq!(s.token.balance(&s.contributor), 5_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(s.escrow.get_pending_release(&1), None);
+}
+
+#[test]
+fn test_confirm_before_time_lock_rejected() {
+    let s = Setup::new();
+
+    s.escrow.propose_release(&1, &s.contributor, &5_000);
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 3_599);
+
+    assert_eq!(
+        s.escrow.try_confirm_release(&1),
+        Err(Ok(Error::RevealTooEarly))
+    );
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert!(s.escrow.get_pending_release(&1).is_some());
+}
+
+#[test]
+fn test_high_value_escrow_rejects_direct_release() {
+    let s = Setup::new();
+
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Err(ExtendedError::HighValueReleaseRequired.into()))
+    );
+
+    // Below the threshold the immediate path still works
+    s.escrow.release_funds(&2, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 500);
+}
+
+#[test]
+fn test_cancelled_release_cannot_be_confirmed() {
+    let s = Setup::new();
+
+    s.escrow.propose_release(&1, &s.contributor, &2_000);
+    s.escrow.cancel_release(&1);
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 3_600);
+
+    assert_eq!(
+        s.escrow.try_confirm_release(&1),
+        Err(Ok(Error::CommitmentNotFound))
+    );
+    assert_eq!(
+        s.escrow.try_cancel_release(&1),
+        Err(Ok(Error::CommitmentNotFound))
+    );
+}
+
+#[test]
+fn test_high_value_escrow_rejects_every_other_release_path() {
+    let s = Setup::new();
+    let expected = Err(Err(ExtendedError::HighValueReleaseRequired.into()));
+
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &100),
+        expected
+    );
+    assert_eq!(
+        s.escrow.try_release_funds_split(
+            &1,
+            &vec![&s.env, s.contributor.clone()],
+            &vec![&s.env, 5_000_i128],
+            &None
+        ),
+        expected
+    );
+    assert_eq!(
+        s.escrow.try_batch_release_funds(&vec![
+            &s.env,
+            ReleaseFundsItem {
+                bounty_id: 1,
+                contributor: s.contributor.clone(),
+            },
+        ]),
+        Err(Err(ExtendedError::HighValueReleaseRequired.into()))
+    );
+
+    let salt = BytesN::from_array(&s.env, &[7; 32]);
+    let hash = s
+        .escrow
+        .compute_release_commitment(&s.contributor, &5_000, &salt);
+    s.escrow.commit_release(&1, &hash);
+    assert_eq!(
+        s.escrow
+            .try_reveal_release(&1, &s.contributor, &5_000, &salt),
+        expected
+    );
+
+    let holder = Address::generate(&s.env);
+    let expiry = s.env.ledger().timestamp() + 300;
+    let capability_id = s.escrow.issue_capability(
+        &s.admin,
+        &holder,
+        &CapabilityAction::Release,
+        &1,
+        &1_000,
+        &expiry,
+        &1,
+    );
+    assert_eq!(
+        s.escrow
+            .try_release_with_capability(&1, &s.contributor, &1_000, &holder, &capability_id),
+        expected
+    );
+
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.escrow.get_escrow_info(&1).remaining_amount, 5_000);
+
+    // The time-locked path still pays out
+    s.escrow.propose_release(&1, &s.contributor, &5_000);
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 3_600);
+    s.escrow.confirm_release(&1);
+    assert_eq!(s.token.balance(&s.contributor), 5_000);
+}
+
+#[test]
+fn test_high_value_escrow_rejects_claims_and_dispute_payout() {
+    let s = Setup::new();
+    let expected = Err(Err(ExtendedError::HighValueReleaseRequired.into()));
+
+    s.escrow
+        .authorize_claim(&1, &s.contributor, &DisputeReason::Other);
+    assert_eq!(s.escrow.try_claim(&1), expected);
+    s.escrow
+        .cancel_pending_claim(&1, &DisputeOutcome::CancelledByAdmin);
+
+    let expires_at = s.env.ledger().timestamp() + 1_000;
+    let ticket_id = s
+        .escrow
+        .issue_claim_ticket(&1, &s.contributor, &5_000, &expires_at);
+    assert_eq!(s.escrow.try_claim_with_ticket(&ticket_id), expected);
+
+    s.escrow.open_dispute(&1);
+    assert_eq!(
+        s.escrow.try_resolve_dispute(
+            &1,
+            &DisputeOutcome::ResolvedByPayout,
+            &Some(s.contributor.clone()),
+        ),
+        expected
+    );
+
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.escrow.get_escrow_info(&1).remaining_amount, 5_000);
+}
@@ -0,0 +1,293 @@
+//! Interface-trait conformance harness.
+//!
+//! Drives a freshly-deployed contract purely through the clients generated from
+//! the shared traits in `traits.rs` (not the inherent `BountyEscrowContractClient`),
+//! so any drift between a trait signature and the exported contract function
+//! surfaces as a failed invocation here.
+
+use crate::traits::{
+    ConfigurableFeeClient, EscrowInterfaceClient, PausableClient, StatusQueryClient,
+};
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus, RefundMode};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+fn create_token_contract<'a>(
+    e: &Env,
+    admin: &Address,
+) -> (token::Client<'a>, token::StellarAssetClient<'a>) {
+    let contract = e.register_stellar_asset_contract_v2(admin.clone());
+    let contract_address = contract.address();
+    (
+        token::Client::new(e, &contract_address),
+        token::StellarAssetClient::new(e, &contract_address),
+    )
+}
+
+struct Setup<'a> {
+    env: Env,
+    escrow: EscrowInterfaceClient<'a>,
+    fee: ConfigurableFeeClient<'a>,
+    pause: PausableClient<'a>,
+    token: token::Client<'a>,
+    token_admin: token::StellarAssetClient<'a>,
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BountyEscrowContract);
+        let admin = Address::generate(&env);
+        let (token, token_admin) = create_token_contract(&env, &admin);
+
+        // `init` is not part of any shared trait, so it is the only call made
+        // through the inherent client.
+        BountyEscrowContractClient::new(&env, &contract_id).init(&admin, &token.address);
+
+        let escrow = EscrowInterfaceClient::new(&env, &contract_id);
+        let fee = ConfigurableFeeClient::new(&env, &contract_id);
+        let pause = PausableClient::new(&env, &contract_id);
+
+        Self {
+            env,
+            escrow,
+            fee,
+            pause,
+            token,
+            token_admin,
+        }
+    }
+}
+
+/// Exercises the full trait surface of a deployed escrow through its
+/// trait-generated clients: lock, release, refund, balance, fee config and
+/// pause flags. Panics on the first behavioral mismatch.
+fn exercise_interfaces(
+    client: &EscrowInterfaceClient,
+    token: &token::Client,
+    token_admin: &token::StellarAssetClient,
+) {
+    let env = &client.env;
+    let fee = ConfigurableFeeClient::new(env, &client.address);
+    let pause = PausableClient::new(env, &client.address);
+
+    let depositor = Address::generate(env);
+    let contributor = Address::generate(env);
+    token_admin.mint(&depositor, &10_000);
+    approve_escrow(token, &depositor, &client.address);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+
+    // Lock + release
+    client.lock_funds(&depositor, &1, &3_000, &deadline);
+    assert_eq!(client.get_escrow_info(&1).status, EscrowStatus::Locked);
+    assert_eq!(client.get_balance(), 3_000);
+
+    client.release_funds(&1, &contributor);
+    assert_eq!(client.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(token.balance(&contributor), 3_000);
+    assert_eq!(client.get_balance(), 0);
+
+    // Lock + refund after deadline
+    client.lock_funds(&depositor, &2, &2_000, &deadline);
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund(&2);
+    assert_eq!(client.get_escrow_info(&2).status, EscrowStatus::Refunded);
+    assert_eq!(token.balance(&depositor), 10_000 - 3_000);
+
+    // Fee configuration round-trips through the trait
+    let recipient = Address::generate(env);
+    fee.update_fee_config(
+        &Some(100),
+        &Some(200),
+        &Some(recipient.clone()),
+        &Some(true),
+    );
+    let cfg = fee.get_fee_config();
+    assert_eq!(cfg.lock_fee_rate, 100);
+    assert_eq!(cfg.release_fee_rate, 200);
+    assert_eq!(cfg.fee_recipient, recipient);
+    assert!(cfg.fee_enabled);
+
+    // Pause flags gate operations reached through the trait
+    pause.set_paused(&Some(true), &None, &None, &None);
+    assert!(pause.get_pause_flags().lock_paused);
+    let deadline = env.ledger().timestamp() + 1_000;
+    assert_eq!(
+        client.try_lock_funds(&depositor, &3, &1_000, &deadline),
+        Err(Ok(Error::FundsPaused))
+    );
+
+    pause.set_paused(&Some(false), &None, &None, &None);
+    assert!(!pause.get_pause_flags().lock_paused);
+    client.lock_funds(&depositor, &3, &1_000, &deadline);
+    assert_eq!(client.get_balance(), 1_000);
+}
+
+/// Approves `escrow` to pull any amount of `from`'s tokens, which `lock_funds` needs.
+fn approve_escrow(token: &token::Client, from: &Address, escrow: &Address) {
+    token.approve(
+        from,
+        escrow,
+        &i128::MAX,
+        &(token.env.ledger().sequence() + 10_000),
+    );
+}
+
+#[test]
+fn test_fresh_contract_satisfies_full_trait_surface() {
+    let s = Setup::new();
+    exercise_interfaces(&s.escrow, &s.token, &s.token_admin);
+}
+
+#[test]
+fn test_trait_errors_propagate_as_contract_errors() {
+    let s = Setup::new();
+    let contributor = Address::generate(&s.env);
+
+    assert_eq!(
+        s.escrow.try_get_escrow_info(&42),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(
+        s.escrow.try_release_funds(&42, &contributor),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(s.escrow.try_refund(&42), Err(Ok(Error::BountyNotFound)));
+}
+
+#[test]
+fn test_trait_fee_config_rejects_invalid_rate() {
+    let s = Setup::new();
+    assert_eq!(
+        s.fee
+            .try_update_fee_config(&Some(crate::MAX_FEE_RATE + 1), &None, &None, &None),
+        Err(Ok(Error::InvalidFeeRate))
+    );
+    assert!(!s.fee.get_fee_config().fee_enabled);
+}
+
+#[test]
+fn test_trait_pause_reason_is_recorded() {
+    let s = Setup::new();
+    let reason = String::from_str(&s.env, "maintenance");
+    s.pause
+        .set_paused(&None, &Some(true), &Some(true), &Some(reason.clone()));
+
+    let flags = s.pause.get_pause_flags();
+    assert!(!flags.lock_paused);
+    assert!(flags.release_paused);
+    assert!(flags.refund_paused);
+    assert_eq!(flags.pause_reason, Some(reason));
+}
+
+#[test]
+fn test_trait_refund_before_deadline_without_approval_fails() {
+    let s = Setup::new();
+    let depositor = Address::generate(&s.env);
+    s.token_admin.mint(&depositor, &1_000);
+    approve_escrow(&s.token, &depositor, &s.escrow.address);
+
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&depositor, &7, &1_000, &deadline);
+
+    assert_eq!(s.escrow.try_refund(&7), Err(Ok(Error::DeadlineNotPassed)));
+    assert_eq!(s.escrow.get_escrow_info(&7).status, EscrowStatus::Locked);
+    assert_eq!(s.escrow.get_balance(), 1_000);
+}
+
+#[test]
+fn test_trait_refund_honours_partial_approval_in_primary_token() {
+    let s = Setup::new();
+    let depositor = Address::generate(&s.env);
+    let recipient = Address::generate(&s.env);
+    s.token_admin.mint(&depositor, &1_000);
+    approve_escrow(&s.token, &depositor, &s.escrow.address);
+
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&depositor, &8, &1_000, &deadline);
+
+    // Approval is admin-only and not part of the shared trait.
+    BountyEscrowContractClient::new(&s.env, &s.escrow.address).approve_refund(
+        &8,
+        &400,
+        &recipient,
+        &RefundMode::Partial,
+    );
+
+    s.escrow.refund(&8);
+
+    let escrow = s.escrow.get_escrow_info(&8);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyRefunded);
+    assert_eq!(escrow.remaining_amount, 600);
+    assert_eq!(s.token.balance(&recipient), 400);
+    assert_eq!(s.escrow.get_balance(), 600);
+}
+
+#[test]
+fn test_trait_release_is_full_release_of_primary_token() {
+    let s = Setup::new();
+    let depositor = Address::generate(&s.env);
+    let contributor = Address::generate(&s.env);
+    s.token_admin.mint(&depositor, &5_000);
+    approve_escrow(&s.token, &depositor, &s.escrow.address);
+
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&depositor, &9, &2_500, &deadline);
+    s.escrow.lock_funds(&depositor, &10, &1_500, &deadline);
+
+    s.escrow.release_funds(&9, &contributor);
+
+    let escrow = s.escrow.get_escrow_info(&9);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(s.token.balance(&contributor), 2_500);
+    // Only the untouched escrow remains in custody.
+    assert_eq!(s.token.balance(&s.escrow.address), 1_500);
+    assert_eq!(s.escrow.get_balance(), 1_500);
+
+    // A released escrow cannot be released again through the trait.
+    assert_eq!(
+        s.escrow.try_release_funds(&9, &contributor),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+fn test_status_query_reports_codes_and_remaining() {
+    let s = Setup::new();
+    let status = StatusQueryClient::new(&s.env, &s.escrow.address);
+    let inherent = BountyEscrowContractClient::new(&s.env, &s.escrow.address);
+    let depositor = Address::generate(&s.env);
+    let contributor = Address::generate(&s.env);
+    s.token_admin.mint(&depositor, &5_000);
+    approve_escrow(&s.token, &depositor, &s.escrow.address);
+
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&depositor, &1, &3_000, &deadline);
+    assert_eq!(status.get_status(&1), EscrowStatus::Locked.code());
+    assert_eq!(status.get_status(&1), 1);
+    assert_eq!(status.get_remaining(&1), 3_000);
+
+    inherent.partial_release(&1, &contributor, &1_000);
+    assert_eq!(status.get_status(&1), 1);
+    assert_eq!(status.get_remaining(&1), 2_000);
+
+    s.escrow.release_funds(&1, &contributor);
+    assert_eq!(status.get_status(&1), 2);
+    assert_eq!(status.get_remaining(&1), 0);
+
+    s.escrow.lock_funds(&depositor, &2, &2_000, &deadline);
+    inherent.open_dispute(&2);
+    assert_eq!(status.get_status(&2), 6);
+
+    assert_eq!(status.try_get_status(&42), Err(Ok(Error::BountyNotFound)));
+    assert_eq!(
+        status.try_get_remaining(&42),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
@@ -18,6 +18,12 @@ fn setup_bounty(env: &Env) -> (BountyEscrowContractClient<'static>, Address, Add
 
     client.init(&admin, &token_id);
     token_admin_client.mint(&depositor, &50_000);
+    token::Client::new(env, &token_id).approve(
+        &depositor,
+        &client.address,
+        &i128::MAX,
+        &(env.ledger().sequence() + 10_000),
+    );
 
     (client, admin, depositor)
 }
@@ -65,6 +65,25 @@ fn test_full_bounty_lifecycle_with_refund() {
 
     token_admin.mint(&depositor, &10000);
 
+    // Approve the escrow contract to pull the deposit
+    let live_until = env.ledger().sequence() + 10_000;
+    env.mock_auths(&[MockAuth {
+        address: &depositor,
+        invoke: &MockAuthInvoke {
+            contract: &token_client.address,
+            fn_name: "approve",
+            args: (
+                depositor.clone(),
+                escrow_client.address.clone(),
+                10000i128,
+                live_until,
+            )
+                .into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    token_client.approve(&depositor, &escrow_client.address, &10000, &live_until);
+
     // 5. Lock funds for a bounty
     let bounty_id = 101u64;
     let initial_amount = 5000i128;
@@ -76,17 +95,7 @@ fn test_full_bounty_lifecycle_with_refund() {
             contract: &escrow_client.address,
             fn_name: "lock_funds",
             args: (depositor.clone(), bounty_id, initial_amount, deadline).into_val(&env),
-            sub_invokes: &[MockAuthInvoke {
-                contract: &token_client.address,
-                fn_name: "transfer",
-                args: (
-                    depositor.clone(),
-                    escrow_client.address.clone(),
-                    initial_amount,
-                )
-                    .into_val(&env),
-                sub_invokes: &[],
-            }],
+            sub_invokes: &[],
         },
     }]);
     assert_eq!(token_client.balance(&depositor), 10000);
@@ -241,6 +250,12 @@ fn test_refund_after_deadline_no_approval_needed() {
 
     escrow_client.init(&admin, &token_client.address);
     token_admin.mint(&depositor, &1000);
+    token_client.approve(
+        &depositor,
+        &escrow_client.address,
+        &i128::MAX,
+        &(env.ledger().sequence() + 10_000),
+    );
 
     let bounty_id = 202;
     let deadline = env.ledger().timestamp() + 100;
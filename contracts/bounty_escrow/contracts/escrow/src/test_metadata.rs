@@ -1,5 +1,10 @@
-use crate::{BountyEscrowContract, BountyEscrowContractClient};
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, Error, EscrowMetadata, ExtendedError,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
 
 #[test]
 fn test_metadata_storage_and_query() {
@@ -29,3 +34,156 @@ fn test_metadata_storage_and_query() {
     assert_eq!(fetched.issue_id, issue_id);
     assert_eq!(fetched.bounty_type, b_type);
 }
+
+fn setup_locked_bounty(env: &Env) -> (BountyEscrowContractClient<'static>, Address, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let depositor = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    token::StellarAssetClient::new(env, &token).mint(&depositor, &1_000);
+    token::Client::new(env, &token).approve(
+        &depositor,
+        &client.address,
+        &i128::MAX,
+        &(env.ledger().sequence() + 10_000),
+    );
+
+    client.init(&admin, &token);
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    (client, admin, depositor)
+}
+
+#[test]
+fn test_metadata_editable_while_locked() {
+    let env = Env::default();
+    let (client, admin, _) = setup_locked_bounty(&env);
+    let b_type = String::from_str(&env, "bounty");
+
+    client.update_metadata(&admin, &1, &1, &10, &b_type);
+    client.update_metadata(&admin, &1, &1, &11, &b_type);
+
+    assert_eq!(client.get_metadata(&1).issue_id, 11);
+}
+
+#[test]
+fn test_metadata_frozen_after_release() {
+    let env = Env::default();
+    let (client, admin, _) = setup_locked_bounty(&env);
+    let b_type = String::from_str(&env, "bounty");
+    client.update_metadata(&admin, &1, &1, &10, &b_type);
+
+    client.release_funds(&1, &Address::generate(&env));
+
+    assert_eq!(
+        client.try_update_metadata(&admin, &1, &2, &20, &b_type),
+        Err(Ok(Error::EscrowTerminal))
+    );
+    assert_eq!(client.get_metadata(&1).repo_id, 1);
+    assert_eq!(client.get_metadata(&1).issue_id, 10);
+}
+
+#[test]
+fn test_metadata_frozen_after_refund() {
+    let env = Env::default();
+    let (client, admin, _) = setup_locked_bounty(&env);
+    let b_type = String::from_str(&env, "bounty");
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_001);
+    client.refund(&1);
+
+    assert_eq!(
+        client.try_update_metadata(&admin, &1, &2, &20, &b_type),
+        Err(Ok(Error::EscrowTerminal))
+    );
+}
+
+#[test]
+fn test_update_metadata_rejects_oversized_bounty_type() {
+    let env = Env::default();
+    let (client, admin, _) = setup_locked_bounty(&env);
+    let b_type = String::from_str(&env, "bounty");
+    client.update_metadata(&admin, &1, &1, &10, &b_type);
+
+    let oversized = String::from_str(&env, "a-bounty-type-label-well-over-the-limit");
+    assert_eq!(
+        client.try_update_metadata(&admin, &1, &2, &20, &oversized),
+        Err(Err(ExtendedError::MetadataTooLarge.into()))
+    );
+    assert_eq!(client.get_metadata(&1).bounty_type, b_type);
+}
+
+fn setup_funded_depositor(
+    env: &Env,
+) -> (
+    BountyEscrowContractClient<'static>,
+    Address,
+    token::Client<'static>,
+) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let depositor = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    token::StellarAssetClient::new(env, &token).mint(&depositor, &1_000);
+    token::Client::new(env, &token).approve(
+        &depositor,
+        &client.address,
+        &i128::MAX,
+        &(env.ledger().sequence() + 10_000),
+    );
+    client.init(&admin, &token);
+
+    (client, depositor, token::Client::new(env, &token))
+}
+
+#[test]
+fn test_lock_funds_with_metadata_stores_both() {
+    let env = Env::default();
+    let (client, depositor, token) = setup_funded_depositor(&env);
+    let metadata = EscrowMetadata {
+        repo_id: 7,
+        issue_id: 42,
+        bounty_type: String::from_str(&env, "bug"),
+    };
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    client.lock_funds_with_metadata(&depositor, &1, &600, &deadline, &metadata);
+
+    assert_eq!(client.get_escrow_info(&1).amount, 600);
+    assert_eq!(token.balance(&client.address), 600);
+    assert_eq!(client.get_metadata(&1), metadata);
+}
+
+#[test]
+fn test_lock_funds_with_invalid_metadata_aborts_lock() {
+    let env = Env::default();
+    let (client, depositor, token) = setup_funded_depositor(&env);
+    let metadata = EscrowMetadata {
+        repo_id: 7,
+        issue_id: 42,
+        bounty_type: String::from_str(&env, "a-bounty-type-label-well-over-the-limit"),
+    };
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    assert_eq!(
+        client.try_lock_funds_with_metadata(&depositor, &1, &600, &deadline, &metadata),
+        Err(Err(ExtendedError::MetadataTooLarge.into()))
+    );
+
+    assert!(client.try_get_escrow_info(&1).is_err());
+    assert_eq!(client.try_get_metadata(&1), Err(Ok(Error::BountyNotFound)));
+    assert_eq!(token.balance(&depositor), 1_000);
+}
@@ -47,6 +47,12 @@ mod metadata_tagging_tests {
             let escrow = create_escrow(&env);
             escrow.init(&admin, &token.address);
             token_admin.mint(&depositor, &10_000_000);
+            token.approve(
+                &depositor,
+                &escrow.address,
+                &i128::MAX,
+                &(env.ledger().sequence() + 10_000),
+            );
             Setup {
                 env,
                 _admin: admin,
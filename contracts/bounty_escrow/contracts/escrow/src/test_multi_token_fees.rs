@@ -19,6 +19,7 @@
 //   3. Correct balance accounting per-token when operating on two instances.
 //   4. Fee rate differences across instances are correctly stored and isolated.
 //   5. Refunding on one instance does not affect the other token's balances.
+//   6. Fee estimates follow the instance's FeeConfig and match real transfers.
 
 #[cfg(test)]
 mod test_multi_token_fees {
@@ -49,6 +50,16 @@ mod test_multi_token_fees {
         client
     }
 
+    /// Approves `escrow` to pull any amount of `from`'s tokens, which `lock_funds` needs.
+    fn approve_escrow(token: &token::Client, from: &Address, escrow: &Address) {
+        token.approve(
+            from,
+            escrow,
+            &i128::MAX,
+            &(token.env.ledger().sequence() + 10_000),
+        );
+    }
+
     // ─── 1. Fee config is independent per contract instance ─────────────────
 
     /// Each escrow instance stores its own FeeConfig. Setting fees on one
@@ -143,7 +154,17 @@ mod test_multi_token_fees {
         let deadline = env.ledger().timestamp() + 1000;
 
         ta_minter.mint(&depositor_a, &amount);
+        approve_escrow(
+            &token::Client::new(&env, &token_a),
+            &depositor_a,
+            &client_a.address,
+        );
         tb_minter.mint(&depositor_b, &amount);
+        approve_escrow(
+            &token::Client::new(&env, &token_b),
+            &depositor_b,
+            &_client_b.address,
+        );
 
         // Operate only on contract_a
         client_a.lock_funds(&depositor_a, &1, &amount, &deadline);
@@ -189,7 +210,17 @@ mod test_multi_token_fees {
         let deadline = env.ledger().timestamp() + 1000;
 
         ta_minter.mint(&depositor_a, &amount_a);
+        approve_escrow(
+            &token::Client::new(&env, &token_a),
+            &depositor_a,
+            &client_a.address,
+        );
         tb_minter.mint(&depositor_b, &amount_b);
+        approve_escrow(
+            &token::Client::new(&env, &token_b),
+            &depositor_b,
+            &client_b.address,
+        );
 
         // Each contract operated independently
         client_a.lock_funds(&depositor_a, &1, &amount_a, &deadline);
@@ -338,7 +369,17 @@ mod test_multi_token_fees {
         let _client_b = make_escrow_instance(&env, &admin, &token_b);
         // Mint both tokens to depositor
         ta_minter.mint(&depositor, &10_000);
+        approve_escrow(
+            &token::Client::new(&env, &token_a),
+            &depositor,
+            &client_a.address,
+        );
         tb_minter.mint(&depositor, &10_000);
+        approve_escrow(
+            &token::Client::new(&env, &token_b),
+            &depositor,
+            &_client_b.address,
+        );
 
         client_a.lock_funds(&depositor, &1, &10_000, &(env.ledger().timestamp() + 1000));
         client_a.release_funds(&1, &contributor);
@@ -380,7 +421,17 @@ mod test_multi_token_fees {
         let deadline = env.ledger().timestamp() + 1000;
 
         ta_minter.mint(&depositor_a, &amount_a);
+        approve_escrow(
+            &token::Client::new(&env, &token_a),
+            &depositor_a,
+            &client_a.address,
+        );
         tb_minter.mint(&depositor_b, &amount_b);
+        approve_escrow(
+            &token::Client::new(&env, &token_b),
+            &depositor_b,
+            &client_b.address,
+        );
 
         client_a.lock_funds(&depositor_a, &42, &amount_a, &deadline);
         client_b.lock_funds(&depositor_b, &42, &amount_b, &deadline);
@@ -423,7 +474,17 @@ mod test_multi_token_fees {
         let deadline = env.ledger().timestamp() + 1000;
 
         ta_minter.mint(&depositor_a, &amount_a);
+        approve_escrow(
+            &token::Client::new(&env, &token_a),
+            &depositor_a,
+            &client_a.address,
+        );
         tb_minter.mint(&depositor_b, &amount_b);
+        approve_escrow(
+            &token::Client::new(&env, &token_b),
+            &depositor_b,
+            &client_b.address,
+        );
 
         // Lock on both contracts
         client_a.lock_funds(&depositor_a, &1, &amount_a, &deadline);
@@ -454,4 +515,67 @@ mod test_multi_token_fees {
             "contract_b's escrow should still have its full remaining_amount"
         );
     }
+
+    // ─── 9. Fee estimates follow each instance's FeeConfig ────────────────────
+
+    /// With fees disabled the estimates report no fee, and the net release
+    /// estimate matches what the contributor actually receives.
+    #[test]
+    fn test_estimates_match_transfers_when_fees_disabled() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let token_admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let contributor = Address::generate(&env);
+
+        let (token, token_client, minter) = make_token(&env, &token_admin);
+        let client = make_escrow_instance(&env, &admin, &token);
+        minter.mint(&depositor, &10_000);
+        approve_escrow(
+            &token::Client::new(&env, &token),
+            &depositor,
+            &client.address,
+        );
+
+        let (lock_net, lock_fee) = client.estimate_lock(&10_000);
+        assert_eq!((lock_net, lock_fee), (10_000, 0));
+        client.lock_funds(&depositor, &1, &10_000, &(env.ledger().timestamp() + 1000));
+        assert_eq!(token_client.balance(&client.address), lock_net);
+
+        let (release_net, release_fee) = client.estimate_release(&10_000);
+        assert_eq!(release_fee, 0);
+        client.release_funds(&1, &contributor);
+        assert_eq!(token_client.balance(&contributor), release_net);
+    }
+
+    /// Enabled rates are applied with the same floor rounding as
+    /// `calculate_fee`, and only to the instance that configured them.
+    #[test]
+    fn test_estimates_apply_configured_rates_per_contract() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let token_admin = Address::generate(&env);
+        let (token_a, _, _) = make_token(&env, &token_admin);
+        let (token_b, _, _) = make_token(&env, &token_admin);
+
+        let client_a = make_escrow_instance(&env, &admin, &token_a);
+        let client_b = make_escrow_instance(&env, &admin, &token_b);
+
+        client_a.update_fee_config(&Some(500), &Some(300), &None, &Some(true));
+
+        assert_eq!(client_a.estimate_lock(&10_000), (9_500, 500));
+        assert_eq!(client_a.estimate_release(&10_000), (9_700, 300));
+        // 999 * 300 / 10_000 = 29.97, floored to 29
+        assert_eq!(client_a.estimate_release(&999), (970, 29));
+        assert_eq!(client_b.estimate_lock(&10_000), (10_000, 0));
+
+        // Rates stay stored but are ignored once fees are switched off
+        client_a.update_fee_config(&None, &None, &None, &Some(false));
+        assert_eq!(client_a.estimate_lock(&10_000), (10_000, 0));
+        assert_eq!(client_a.estimate_release(&10_000), (10_000, 0));
+    }
 }
@@ -63,6 +63,12 @@ impl<'a> InvSetup<'a> {
         escrow.init(&admin, &token.address);
 
         token_admin.mint(&depositor, &100_000_000);
+        token.approve(
+            &depositor,
+            &escrow.address,
+            &i128::MAX,
+            &(env.ledger().sequence() + 10_000),
+        );
 
         Self {
             env,
@@ -76,6 +76,12 @@ impl<'a> Setup<'a> {
 
         // Mint a generous amount so tests never starve for tokens.
         token_admin.mint(&depositor, &10_000_000_000_i128);
+        token.approve(
+            &depositor,
+            &escrow.address,
+            &i128::MAX,
+            &(env.ledger().sequence() + 10_000),
+        );
 
         Self {
             env,
@@ -155,6 +161,25 @@ fn test_leave_one_unit_remainder() {
     assert_eq!(s.token.balance(&s.escrow.address), 1);
 }
 
+/// A 1-unit remainder is not stuck: `release_funds` pays out whatever is
+/// left, however small, and closes the escrow as Released.
+#[test]
+fn test_release_funds_closes_one_unit_remainder() {
+    let s = Setup::new();
+    let amount = 999_999_i128;
+    s.lock(30, amount);
+    s.escrow.partial_release(&30, &s.contributor, &(amount - 1));
+
+    s.escrow.release_funds(&30, &s.contributor);
+
+    let info = s.escrow.get_escrow_info(&30);
+    assert_eq!(info.remaining_amount, 0);
+    assert_eq!(info.status, EscrowStatus::Released);
+    assert_eq!(s.token.balance(&s.contributor), amount);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+    assert_eq!(s.escrow.get_escrow_released_amount(&30), amount);
+}
+
 /// Leave 2 units, drain them one-by-one. Both steps must succeed.
 #[test]
 fn test_drain_last_two_units_individually() {
@@ -711,3 +736,46 @@ fn test_remaining_amounts_sum_equals_contract_balance() {
     );
     assert_eq!(info_a.status, EscrowStatus::Released); // A fully drained
 }
+
+// ===========================================================================
+// 18. Remaining-by-token view after partial payouts
+// ===========================================================================
+
+/// After a partial release the per-token view lists only the escrow token with
+/// its nonzero remainder, and the total matches `remaining_amount`. Once fully
+/// drained the view is empty.
+#[test]
+fn test_remaining_by_token_reflects_partial_release() {
+    let s = Setup::new();
+    s.lock(27, 1_000_i128);
+
+    s.escrow.partial_release(&27, &s.contributor, &350_i128);
+
+    let by_token = s.escrow.get_remaining_by_token(&27);
+    assert_eq!(by_token.len(), 1);
+    assert_eq!(
+        by_token.get(0).unwrap(),
+        (s.token.address.clone(), 650_i128)
+    );
+
+    let info = s.escrow.get_escrow_info(&27);
+    assert_eq!(s.escrow.get_total_remaining(&27), info.remaining_amount);
+
+    s.escrow.partial_release(&27, &s.contributor, &650_i128);
+
+    assert_eq!(s.escrow.get_remaining_by_token(&27).len(), 0);
+    assert_eq!(s.escrow.get_total_remaining(&27), 0);
+}
+
+#[test]
+fn test_remaining_by_token_unknown_bounty() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_get_remaining_by_token(&999),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(
+        s.escrow.try_get_total_remaining(&999),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
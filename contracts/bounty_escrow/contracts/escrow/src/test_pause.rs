@@ -24,6 +24,16 @@ fn create_escrow_contract<'a>(e: &Env) -> (BountyEscrowContractClient<'a>, Addre
     (client, contract_id)
 }
 
+/// Approves `escrow` to pull any amount of `from`'s tokens, which `lock_funds` needs.
+fn approve_escrow(token: &token::Client, from: &Address, escrow: &Address) {
+    token.approve(
+        from,
+        escrow,
+        &i128::MAX,
+        &(token.env.ledger().sequence() + 10_000),
+    );
+}
+
 #[test]
 fn test_granular_pause_lock() {
     let env = Env::default();
@@ -44,6 +54,7 @@ fn test_granular_pause_lock() {
     assert!(!flags.refund_paused);
 
     token_admin_client.mint(&depositor, &1000);
+    approve_escrow(&token_client, &depositor, &escrow_client.address);
 
     let bounty_id_1: u64 = 1;
     let deadline = env.ledger().timestamp() + 1000;
@@ -79,6 +90,7 @@ fn test_granular_pause_release() {
 
     escrow_client.init(&admin, &token_client.address);
     token_admin_client.mint(&depositor, &1000);
+    approve_escrow(&token_client, &depositor, &escrow_client.address);
 
     let bounty_id: u64 = 1;
     let deadline = env.ledger().timestamp() + 1000;
@@ -112,6 +124,7 @@ fn test_granular_pause_refund() {
 
     escrow_client.init(&admin, &token_client.address);
     token_admin_client.mint(&depositor, &1000);
+    approve_escrow(&token_client, &depositor, &escrow_client.address);
 
     let bounty_id: u64 = 1;
     let deadline = env.ledger().timestamp() + 1000;
@@ -221,6 +234,7 @@ fn test_batch_lock_funds_while_paused_fails() {
 
     escrow_client.init(&admin, &token_client.address);
     token_admin_client.mint(&depositor, &1000);
+    approve_escrow(&token_client, &depositor, &escrow_client.address);
 
     escrow_client.set_paused(&Some(true), &None, &None, &None);
 
@@ -260,6 +274,7 @@ fn test_batch_release_funds_while_paused_fails() {
 
     escrow_client.init(&admin, &token_client.address);
     token_admin_client.mint(&depositor, &1000);
+    approve_escrow(&token_client, &depositor, &escrow_client.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow_client.lock_funds(&depositor, &1u64, &100, &deadline);
@@ -293,6 +308,7 @@ fn test_operations_resume_after_unpause() {
 
     escrow_client.init(&admin, &token_client.address);
     token_admin_client.mint(&depositor, &1000);
+    approve_escrow(&token_client, &depositor, &escrow_client.address);
 
     // Pause everything
     escrow_client.set_paused(&Some(true), &Some(true), &Some(true), &None);
@@ -333,6 +349,7 @@ fn test_lock_funds_while_paused_no_state_change() {
 
     escrow_client.init(&admin, &token_client.address);
     token_admin_client.mint(&depositor, &1000);
+    approve_escrow(&token_client, &depositor, &escrow_client.address);
 
     escrow_client.set_paused(&Some(true), &None, &None, &None);
 
@@ -383,6 +400,7 @@ fn test_emergency_withdraw_succeeds() {
 
     escrow_client.init(&admin, &token_client.address);
     token_admin_client.mint(&depositor, &1000);
+    approve_escrow(&token_client, &depositor, &escrow_client.address);
 
     let deadline = env.ledger().timestamp() + 1000;
     escrow_client.lock_funds(&depositor, &1u64, &500i128, &deadline);
@@ -429,6 +447,7 @@ fn setup_rbac_env<'a>(
 
     let depositor = Address::generate(env);
     token_admin_client.mint(&depositor, &1000);
+    approve_escrow(&token_client, &depositor, &contract_id);
     let deadline = env.ledger().timestamp() + 1000;
     escrow_client.lock_funds(&depositor, &1u64, &500i128, &deadline);
 
@@ -454,6 +473,58 @@ fn test_rbac_admin_can_emergency_withdraw_when_paused() {
     assert_eq!(token_client.balance(&target), 500);
 }
 
+/// emergency_withdraw_token recovers both the escrow token and a token that
+/// was sent to the contract directly, emitting one event per token.
+#[test]
+fn test_emergency_withdraw_token_recovers_each_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, _, token_client, escrow_client) = setup_rbac_env(&env);
+    let (stray_client, stray_admin_client) = create_token_contract(&env, &admin);
+    stray_admin_client.mint(&escrow_client.address, &300);
+    let target = Address::generate(&env);
+
+    escrow_client.set_paused(&Some(true), &None, &None, &None);
+    escrow_client.emergency_withdraw_token(&stray_client.address, &target);
+    let stray_event = env.events().all().last().unwrap();
+    escrow_client.emergency_withdraw_token(&token_client.address, &target);
+    let escrow_event = env.events().all().last().unwrap();
+
+    assert_eq!(stray_client.balance(&escrow_client.address), 0);
+    assert_eq!(stray_client.balance(&target), 300);
+    assert_eq!(token_client.balance(&escrow_client.address), 0);
+    assert_eq!(token_client.balance(&target), 500);
+    assert_eq!(
+        escrow_client.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+
+    let stray: events::EmergencyWithdrawEvent = stray_event.2.try_into_val(&env).unwrap();
+    assert_eq!(stray.token, stray_client.address);
+    assert_eq!(stray.amount, 300);
+    let escrowed: events::EmergencyWithdrawEvent = escrow_event.2.try_into_val(&env).unwrap();
+    assert_eq!(escrowed.token, token_client.address);
+    assert_eq!(escrowed.amount, 500);
+}
+
+/// emergency_withdraw_token is gated on the pause like emergency_withdraw.
+#[test]
+fn test_emergency_withdraw_token_requires_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, _, _token_client, escrow_client) = setup_rbac_env(&env);
+    let (stray_client, stray_admin_client) = create_token_contract(&env, &admin);
+    stray_admin_client.mint(&escrow_client.address, &300);
+
+    assert_eq!(
+        escrow_client.try_emergency_withdraw_token(&stray_client.address, &admin),
+        Err(Ok(Error::NotPaused))
+    );
+    assert_eq!(stray_client.balance(&escrow_client.address), 300);
+}
+
 /// Operator/non-admin role CANNOT perform emergency_withdraw — auth rejected.
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
@@ -486,7 +557,7 @@ fn test_rbac_emergency_withdraw_emits_event() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (admin, _, _token_client, escrow_client) = setup_rbac_env(&env);
+    let (admin, _, token_client, escrow_client) = setup_rbac_env(&env);
     let target = Address::generate(&env);
 
     escrow_client.set_paused(&Some(true), &None, &None, &None);
@@ -507,6 +578,7 @@ fn test_rbac_emergency_withdraw_emits_event() {
                     recipient: target.clone(),
                     amount: 500i128,
                     timestamp: env.ledger().timestamp(),
+                    token: token_client.address.clone(),
                 }
                 .into_val(&env)
             ),
@@ -596,6 +668,7 @@ fn test_rbac_emergency_withdraw_drains_all_bounties() {
 
     escrow_client.init(&admin, &token_client.address);
     token_admin_client.mint(&depositor, &3000);
+    approve_escrow(&token_client, &depositor, &escrow_client.address);
 
     let deadline = env.ledger().timestamp() + 1000;
 
@@ -632,6 +705,12 @@ fn test_rbac_after_emergency_withdraw_can_unpause_and_reuse() {
     let new_depositor = Address::generate(&env);
     let token_admin_client = token::StellarAssetClient::new(&env, &token_client.address);
     token_admin_client.mint(&new_depositor, &500);
+    token_client.approve(
+        &new_depositor,
+        &escrow_client.address,
+        &200,
+        &(env.ledger().sequence() + 10_000),
+    );
 
     let deadline = env.ledger().timestamp() + 2000;
     escrow_client.lock_funds(&new_depositor, &99u64, &200i128, &deadline);
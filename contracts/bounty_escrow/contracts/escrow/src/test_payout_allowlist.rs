@@ -0,0 +1,138 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env};
+
+struct TestSetup<'a> {
+    env: Env,
+    depositor: Address,
+    token: token::Client<'a>,
+    escrow: BountyEscrowContractClient<'a>,
+}
+
+impl<'a> TestSetup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let token_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let escrow = BountyEscrowContractClient::new(
+            &env,
+            &env.register_contract(None, BountyEscrowContract),
+        );
+
+        escrow.init(&admin, &token_address);
+        token::StellarAssetClient::new(&env, &token_address).mint(&depositor, &1_000_000);
+        token::Client::new(
+            &env,
+            &token::StellarAssetClient::new(&env, &token_address).address,
+        )
+        .approve(
+            &depositor,
+            &escrow.address,
+            &i128::MAX,
+            &(env.ledger().sequence() + 10_000),
+        );
+
+        Self {
+            token: token::Client::new(&env, &token_address),
+            env,
+            depositor,
+            escrow,
+        }
+    }
+
+    fn lock(&self, bounty_id: u64, amount: i128) {
+        let deadline = self.env.ledger().timestamp() + 1_000;
+        self.escrow
+            .lock_funds(&self.depositor, &bounty_id, &amount, &deadline);
+    }
+}
+
+#[test]
+fn test_allowlist_disabled_by_default() {
+    let setup = TestSetup::new();
+    let contributor = Address::generate(&setup.env);
+    setup.lock(1, 1_000);
+
+    assert!(!setup.escrow.payout_allowlist_enabled());
+    setup.escrow.release_funds(&1, &contributor);
+    assert_eq!(setup.token.balance(&contributor), 1_000);
+}
+
+#[test]
+fn test_allowlist_rejects_unlisted_recipient_when_enabled() {
+    let setup = TestSetup::new();
+    let outsider = Address::generate(&setup.env);
+    setup.lock(1, 1_000);
+    setup.lock(2, 1_000);
+    setup.escrow.set_payout_allowlist_enabled(&true);
+
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &outsider),
+        Err(Ok(Error::RecipientNotAllowed))
+    );
+    assert_eq!(
+        setup.escrow.try_partial_release(&1, &outsider, &100),
+        Err(Ok(Error::RecipientNotAllowed))
+    );
+    let items = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: outsider.clone(),
+        },
+    ];
+    assert_eq!(
+        setup.escrow.try_batch_release_funds(&items),
+        Err(Ok(Error::RecipientNotAllowed))
+    );
+    assert_eq!(setup.token.balance(&outsider), 0);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+}
+
+#[test]
+fn test_allowlist_accepts_listed_recipient() {
+    let setup = TestSetup::new();
+    let contributor = Address::generate(&setup.env);
+    setup.lock(1, 1_000);
+    setup.lock(2, 2_000);
+    setup.escrow.set_payout_allowlist_enabled(&true);
+    setup.escrow.add_payout_address(&contributor);
+    assert!(setup.escrow.is_payout_address(&contributor));
+
+    setup.escrow.release_funds(&1, &contributor);
+    let items = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: contributor.clone(),
+        },
+    ];
+    setup.escrow.batch_release_funds(&items);
+    assert_eq!(setup.token.balance(&contributor), 3_000);
+}
+
+#[test]
+fn test_removed_address_is_rejected_until_allowlist_disabled() {
+    let setup = TestSetup::new();
+    let contributor = Address::generate(&setup.env);
+    setup.lock(1, 1_000);
+    setup.escrow.set_payout_allowlist_enabled(&true);
+    setup.escrow.add_payout_address(&contributor);
+    setup.escrow.remove_payout_address(&contributor);
+
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &contributor),
+        Err(Ok(Error::RecipientNotAllowed))
+    );
+
+    setup.escrow.set_payout_allowlist_enabled(&false);
+    setup.escrow.release_funds(&1, &contributor);
+    assert_eq!(setup.token.balance(&contributor), 1_000);
+}
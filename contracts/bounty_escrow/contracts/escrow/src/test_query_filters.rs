@@ -24,6 +24,16 @@ fn create_escrow(env: &Env) -> BountyEscrowContractClient<'static> {
     BountyEscrowContractClient::new(env, &id)
 }
 
+/// Approves `escrow` to pull any amount of `from`'s tokens, which `lock_funds` needs.
+fn approve_escrow(token: &token::Client, from: &Address, escrow: &Address) {
+    token.approve(
+        from,
+        escrow,
+        &i128::MAX,
+        &(token.env.ledger().sequence() + 10_000),
+    );
+}
+
 struct Setup {
     env: Env,
     depositor: Address,
@@ -44,6 +54,7 @@ impl Setup {
         let escrow = create_escrow(&env);
         escrow.init(&admin, &token.address);
         token_admin.mint(&depositor, &10_000_000);
+        approve_escrow(&token, &depositor, &escrow.address);
         Setup {
             env,
             depositor,
@@ -263,6 +274,11 @@ fn test_query_by_depositor_returns_only_that_depositors_escrows() {
     let dl = s.env.ledger().timestamp() + 1000;
     let depositor2 = Address::generate(&s.env);
     s.token_admin.mint(&depositor2, &10_000);
+    approve_escrow(
+        &token::Client::new(&s.env, &s.token_admin.address),
+        &depositor2,
+        &s.escrow.address,
+    );
 
     s.escrow.lock_funds(&s.depositor, &1, &100, &dl);
     s.escrow.lock_funds(&s.depositor, &2, &200, &dl);
@@ -287,6 +303,146 @@ fn test_query_by_depositor_returns_empty_for_unknown_address() {
     assert_eq!(results.len(), 0);
 }
 
+#[test]
+fn test_query_by_depositor_three_depositors_paginate_own_ids() {
+    let s = Setup::new();
+    let dl = s.env.ledger().timestamp() + 1000;
+    let depositor2 = Address::generate(&s.env);
+    let depositor3 = Address::generate(&s.env);
+    s.token_admin.mint(&depositor2, &10_000);
+    approve_escrow(
+        &token::Client::new(&s.env, &s.token_admin.address),
+        &depositor2,
+        &s.escrow.address,
+    );
+    s.token_admin.mint(&depositor3, &10_000);
+    approve_escrow(
+        &token::Client::new(&s.env, &s.token_admin.address),
+        &depositor3,
+        &s.escrow.address,
+    );
+
+    // Interleave locks so each depositor's IDs are non-contiguous
+    for round in 0u64..3 {
+        s.escrow
+            .lock_funds(&s.depositor, &(round * 3 + 1), &100, &dl);
+        s.escrow
+            .lock_funds(&depositor2, &(round * 3 + 2), &100, &dl);
+        s.escrow
+            .lock_funds(&depositor3, &(round * 3 + 3), &100, &dl);
+    }
+
+    for (depositor, first_id) in [(&s.depositor, 1u64), (&depositor2, 2), (&depositor3, 3)] {
+        let page1 = s.escrow.query_escrows_by_depositor(depositor, &0, &2);
+        let page2 = s.escrow.query_escrows_by_depositor(depositor, &2, &2);
+        assert_eq!(page1.len(), 2);
+        assert_eq!(page2.len(), 1);
+
+        let ids = [
+            page1.get(0).unwrap().bounty_id,
+            page1.get(1).unwrap().bounty_id,
+            page2.get(0).unwrap().bounty_id,
+        ];
+        assert_eq!(ids, [first_id, first_id + 3, first_id + 6]);
+        for i in 0..page1.len() {
+            assert_eq!(&page1.get(i).unwrap().escrow.depositor, depositor);
+        }
+        assert_eq!(&page2.get(0).unwrap().escrow.depositor, depositor);
+    }
+}
+
+#[test]
+fn test_query_by_depositor_huge_limit_does_not_overflow() {
+    let s = Setup::new();
+    let dl = s.env.ledger().timestamp() + 1000;
+    s.escrow.lock_funds(&s.depositor, &1, &100, &dl);
+    s.escrow.lock_funds(&s.depositor, &2, &200, &dl);
+
+    let results = s
+        .escrow
+        .query_escrows_by_depositor(&s.depositor, &1, &u32::MAX);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results.get(0).unwrap().bounty_id, 2);
+}
+
+#[test]
+fn test_depositor_summary_after_release_and_refund() {
+    let s = Setup::new();
+    let dl = s.env.ledger().timestamp() + 1000;
+    let other = Address::generate(&s.env);
+    s.token_admin.mint(&other, &10_000);
+    approve_escrow(
+        &token::Client::new(&s.env, &s.token_admin.address),
+        &other,
+        &s.escrow.address,
+    );
+
+    s.escrow.lock_funds(&s.depositor, &1, &100, &dl);
+    s.escrow.lock_funds(&s.depositor, &2, &200, &dl);
+    s.escrow.lock_funds(&s.depositor, &3, &300, &dl);
+    s.escrow.lock_funds(&other, &4, &400, &dl);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    s.env.ledger().set_timestamp(dl + 1);
+    s.escrow.refund(&2);
+
+    let summary = s.escrow.get_depositor_summary(&s.depositor);
+    assert_eq!(summary.active_count, 1);
+    assert_eq!(summary.total_locked, 300);
+    assert_eq!(summary.total_released, 100);
+    assert_eq!(summary.total_refunded, 200);
+
+    let other_summary = s.escrow.get_depositor_summary(&other);
+    assert_eq!(other_summary.active_count, 1);
+    assert_eq!(other_summary.total_locked, 400);
+}
+
+#[test]
+fn test_depositor_summary_counts_partial_release_as_released() {
+    let s = Setup::new();
+    let dl = s.env.ledger().timestamp() + 1000;
+
+    s.escrow.lock_funds(&s.depositor, &1, &500, &dl);
+    s.escrow.partial_release(&1, &s.contributor, &150);
+
+    let summary = s.escrow.get_depositor_summary(&s.depositor);
+    assert_eq!(summary.active_count, 1);
+    assert_eq!(summary.total_locked, 350);
+    assert_eq!(summary.total_released, 150);
+    assert_eq!(summary.total_refunded, 0);
+}
+
+#[test]
+fn test_depositor_summary_splits_escrow_released_then_refunded() {
+    let s = Setup::new();
+    let dl = s.env.ledger().timestamp() + 1000;
+
+    s.escrow.lock_funds(&s.depositor, &1, &500, &dl);
+    s.escrow.partial_release(&1, &s.contributor, &150);
+    s.env.ledger().set_timestamp(dl + 1);
+    s.escrow.refund(&1);
+
+    let summary = s.escrow.get_depositor_summary(&s.depositor);
+    assert_eq!(summary.active_count, 0);
+    assert_eq!(summary.total_locked, 0);
+    assert_eq!(summary.total_released, 150);
+    assert_eq!(summary.total_refunded, 350);
+    assert_eq!(
+        summary.total_released + summary.total_refunded + summary.total_locked,
+        500
+    );
+}
+
+#[test]
+fn test_depositor_summary_empty_for_unknown_address() {
+    let s = Setup::new();
+    let summary = s.escrow.get_depositor_summary(&Address::generate(&s.env));
+    assert_eq!(summary.active_count, 0);
+    assert_eq!(summary.total_locked, 0);
+    assert_eq!(summary.total_released, 0);
+    assert_eq!(summary.total_refunded, 0);
+}
+
 // get_escrow_ids_by_status tests
 
 #[test]
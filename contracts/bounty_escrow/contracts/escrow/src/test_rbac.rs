@@ -160,6 +160,12 @@ fn test_depositor_permissions() {
     // Setup token balance
     let sac_client = token::StellarAssetClient::new(&setup.env, &setup.token_id);
     sac_client.mint(&setup.depositor, &amount);
+    token::Client::new(&setup.env, &setup.token_id).approve(
+        &setup.depositor,
+        &setup.client.address,
+        &amount,
+        &(setup.env.ledger().sequence() + 10_000),
+    );
 
     // Signatures: lock_funds(depositor, bounty_id, amount, deadline)
     setup
@@ -189,6 +195,12 @@ fn test_random_cannot_lock_funds_for_depositor() {
     // Mint tokens
     let sac_client = token::StellarAssetClient::new(&env, &token_id);
     sac_client.mint(&depositor, &1000i128);
+    token::Client::new(&env, &sac_client.address).approve(
+        &depositor,
+        &client.address,
+        &i128::MAX,
+        &(env.ledger().sequence() + 10_000),
+    );
 
     // Create fresh env WITHOUT mock_all_auths for the actual test call
     let env2 = Env::default();
@@ -1,5 +1,10 @@
 use super::*;
-use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, token, Address, Env};
+use soroban_sdk::{
+    testutils::Address as _,
+    testutils::Ledger as _,
+    testutils::{MockAuth, MockAuthInvoke},
+    token, Address, Env, IntoVal,
+};
 
 // ---------------------------------------------------------------------------
 // Test setup helper
@@ -37,6 +42,12 @@ impl<'a> RenewTestSetup<'a> {
 
         // Give depositor plenty of tokens
         token_admin.mint(&depositor, &10_000_000);
+        token.approve(
+            &depositor,
+            &escrow.address,
+            &i128::MAX,
+            &(env.ledger().sequence() + 10_000),
+        );
 
         Self {
             env,
@@ -455,3 +466,67 @@ fn test_renew_then_release_then_new_cycle() {
     let history_after = s.escrow.get_renewal_history(&id_1);
     assert_eq!(history_after.len(), 2);
 }
+
+// ===========================================================================
+// Refund Deadline Extension Tests
+// ===========================================================================
+
+#[test]
+fn test_admin_extends_refund_deadline_without_depositor_auth() {
+    let s = RenewTestSetup::new();
+    let bounty_id = 400_u64;
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.lock_bounty(bounty_id, 5_000, deadline);
+
+    let new_deadline = deadline + 5_000;
+    s.escrow
+        .mock_auths(&[MockAuth {
+            address: &s.admin,
+            invoke: &MockAuthInvoke {
+                contract: &s.escrow.address,
+                fn_name: "extend_refund_deadline",
+                args: (bounty_id, s.admin.clone(), new_deadline).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }])
+        .extend_refund_deadline(&bounty_id, &s.admin, &new_deadline);
+
+    assert_eq!(s.escrow.get_escrow_info(&bounty_id).deadline, new_deadline);
+}
+
+#[test]
+fn test_depositor_extends_refund_deadline() {
+    let s = RenewTestSetup::new();
+    let bounty_id = 401_u64;
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.lock_bounty(bounty_id, 5_000, deadline);
+
+    s.escrow
+        .extend_refund_deadline(&bounty_id, &s.depositor, &(deadline + 100));
+
+    assert_eq!(
+        s.escrow.get_escrow_info(&bounty_id).deadline,
+        deadline + 100
+    );
+    assert_eq!(
+        s.escrow
+            .try_extend_refund_deadline(&bounty_id, &s.depositor, &deadline),
+        Err(Ok(Error::InvalidRenewal))
+    );
+}
+
+#[test]
+fn test_unrelated_address_cannot_extend_refund_deadline() {
+    let s = RenewTestSetup::new();
+    let bounty_id = 402_u64;
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.lock_bounty(bounty_id, 5_000, deadline);
+
+    let stranger = Address::generate(&s.env);
+    let res = s
+        .escrow
+        .try_extend_refund_deadline(&bounty_id, &stranger, &(deadline + 100));
+
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+    assert_eq!(s.escrow.get_escrow_info(&bounty_id).deadline, deadline);
+}
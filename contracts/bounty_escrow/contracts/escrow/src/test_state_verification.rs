@@ -26,6 +26,12 @@ mod test {
 
         client.init(&admin, &token_address);
         token_admin_client.mint(&depositor, &10_000);
+        token::Client::new(env, &token_address).approve(
+            &depositor,
+            &client.address,
+            &i128::MAX,
+            &(env.ledger().sequence() + 10_000),
+        );
         (client, contract_id, admin, depositor, token_address)
     }
 
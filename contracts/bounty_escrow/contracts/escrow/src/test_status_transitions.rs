@@ -49,6 +49,12 @@ impl<'a> TestSetup<'a> {
 
         escrow.init(&admin, &token.address);
         token_admin.mint(&depositor, &1_000_000);
+        token.approve(
+            &depositor,
+            &escrow.address,
+            &i128::MAX,
+            &(env.ledger().sequence() + 10_000),
+        );
 
         Self {
             env,
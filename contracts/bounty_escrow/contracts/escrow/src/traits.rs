@@ -1,8 +1,9 @@
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::{contractclient, Address, Env, String};
 
 /// Shared interface for escrow functionality
 /// Both bounty_escrow and program-escrow should implement this
 #[allow(dead_code)]
+#[contractclient(name = "EscrowInterfaceClient")]
 pub trait EscrowInterface {
     /// Lock funds for a bounty
     fn lock_funds(
@@ -17,6 +18,11 @@ pub trait EscrowInterface {
     fn release_funds(env: &Env, bounty_id: u64, contributor: Address) -> Result<(), crate::Error>;
 
     /// Refund funds to depositor
+    ///
+    /// Refunds are always paid in the contract's configured token. Amount,
+    /// recipient, and mode come from a pending admin approval when one
+    /// exists; otherwise the full remaining balance goes to the depositor
+    /// once the deadline has passed.
     fn refund(env: &Env, bounty_id: u64) -> Result<(), crate::Error>;
 
     /// Get escrow info
@@ -26,6 +32,65 @@ pub trait EscrowInterface {
     fn get_balance(env: &Env) -> Result<i128, crate::Error>;
 }
 
+/// Contract-agnostic status reads for frontends that integrate several
+/// escrow contracts.
+///
+/// `get_status` returns a stable code, independent of enum ordering:
+///
+/// | Code | Status              |
+/// |------|---------------------|
+/// | 1    | `Locked`            |
+/// | 2    | `Released`          |
+/// | 3    | `Refunded`          |
+/// | 4    | `PartiallyRefunded` |
+/// | 5    | `Template`          |
+/// | 6    | `Disputed`          |
+///
+/// Codes are never reused; new statuses get the next free code.
+#[allow(dead_code)]
+#[contractclient(name = "StatusQueryClient")]
+pub trait StatusQuery {
+    /// Stable status code of an escrow (see table above)
+    fn get_status(env: &Env, id: u64) -> Result<u32, crate::Error>;
+
+    /// Amount still held by an escrow
+    fn get_remaining(env: &Env, id: u64) -> Result<i128, crate::Error>;
+}
+
+/// Shared interface for fee configuration
+#[allow(dead_code)]
+#[contractclient(name = "ConfigurableFeeClient")]
+pub trait ConfigurableFee {
+    /// Update fee rates, recipient, and enabled flag (admin only)
+    fn update_fee_config(
+        env: &Env,
+        lock_fee_rate: Option<i128>,
+        release_fee_rate: Option<i128>,
+        fee_recipient: Option<Address>,
+        fee_enabled: Option<bool>,
+    ) -> Result<(), crate::Error>;
+
+    /// Get current fee configuration
+    fn get_fee_config(env: &Env) -> crate::FeeConfig;
+}
+
+/// Shared interface for pausing contract operations
+#[allow(dead_code)]
+#[contractclient(name = "PausableClient")]
+pub trait Pausable {
+    /// Set per-operation pause flags (admin only)
+    fn set_paused(
+        env: &Env,
+        lock: Option<bool>,
+        release: Option<bool>,
+        refund: Option<bool>,
+        reason: Option<String>,
+    ) -> Result<(), crate::Error>;
+
+    /// Get current pause flags
+    fn get_pause_flags(env: &Env) -> crate::PauseFlags;
+}
+
 /// Shared interface for contract upgrades
 #[allow(dead_code)]
 pub trait UpgradeInterface {